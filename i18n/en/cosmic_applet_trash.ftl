@@ -1,7 +1,8 @@
 # Trash applet strings
 
 trash = Trash
-open-trash = Open trash…
+open-trash = Open trash in files…
+open-trash-action = Open Trash
 empty-trash = Empty Trash
 restore-items = Restore Items
 items-count = { $count ->
@@ -9,3 +10,267 @@ items-count = { $count ->
     [1] 1 item
     *[other] { $count } items
 }
+
+# Sizes (value is already formatted for the locale)
+size-bytes = { $value } B
+size-kib = { $value } KiB
+size-mib = { $value } MiB
+size-gib = { $value } GiB
+size-tib = { $value } TiB
+size-kb = { $value } kB
+size-mb = { $value } MB
+size-gb = { $value } GB
+size-tb = { $value } TB
+folder-items = { $count ->
+    [one] 1 item
+    *[other] { $count } items
+}
+
+# Durations
+duration-less-than-minute = less than a minute
+duration-minutes = { $count ->
+    [one] 1 minute
+    *[other] { $count } minutes
+}
+duration-hours = { $count ->
+    [one] 1 hour
+    *[other] { $count } hours
+}
+duration-days = { $count ->
+    [one] 1 day
+    *[other] { $count } days
+}
+
+# Panel tooltip
+tooltip-contents = { $count ->
+    [one] 1 item
+    *[other] { $count } items
+} · { $size }
+tooltip-oldest = Oldest item deleted { $age } ago
+tooltip-next-cleanup = Next cleanup in { $duration }
+tooltip-next-cleanup-soon = Next cleanup within the hour
+tooltip-recent = Recent { $position }/{ $count }: { $name }, deleted { $age } ago
+
+# Popup
+recently-deleted = Recently deleted
+deleted-ago = { $age } ago
+search-trash = Search trash
+search-no-match = No items match the search
+trash-is-empty = Trash is empty
+empty-trash-menu = Empty trash…
+empty-trash-except-pinned = Empty all except pinned…
+secure-empty-trash = Securely empty trash…
+secure-erase-progress = Securely erasing { $name }… { $percent }%
+secure-erase-copy-on-write = { $filesystem } is copy-on-write: old contents may remain on disk
+secure-erase-limitations = Overwriting is not reliable on SSDs or copy-on-write filesystems (Btrfs, ZFS): old contents may survive elsewhere on disk.
+export-list = Export list…
+export-dialog-title = Export trash list
+show-restored = Show restored item…
+settings-menu = Settings…
+marked-count = { $count } selected
+clear = Clear
+cancel = Cancel
+cannot-undo = This cannot be undone.
+confirm-empty = { $count ->
+    [one] Permanently delete 1 item from the trash?
+    *[other] Permanently delete { $count } items from the trash?
+}
+confirm-empty-action = Empty trash
+confirm-delete = Permanently delete { $name }?
+confirm-delete-marked = { $count ->
+    [one] Permanently delete 1 item?
+    *[other] Permanently delete { $count } items?
+}
+confirm-add-rule = { $count ->
+    [one] This rule permanently deletes 1 item already in the trash. Add it?
+    *[other] This rule permanently deletes { $count } items already in the trash. Add it?
+}
+confirm-remove-rule = { $count ->
+    [one] Without this rule, 1 item already in the trash is permanently deleted. Remove it?
+    *[other] Without this rule, { $count } items already in the trash are permanently deleted. Remove it?
+}
+confirm-change-setting = { $count ->
+    [one] This permanently deletes 1 item already in the trash. Change the setting?
+    *[other] This permanently deletes { $count } items already in the trash. Change the setting?
+}
+confirm-change-setting-action = Change setting
+
+# Items list
+list-files = Files
+list-actions = Actions
+action-open = Open
+action-reveal = Show original location
+action-restore = Restore
+action-delete = Delete
+pin = Pin
+unpin = Unpin
+pin-description = Pinned items are kept when emptying the trash
+secure-delete = Securely delete
+
+# Preview
+preview-loading = Loading preview…
+preview-more = { $count ->
+    [one] … and 1 more
+    *[other] … and { $count } more
+}
+preview-original-location = Original location
+preview-permissions = Permissions
+preview-modified = Modified
+
+# Settings
+settings = Settings
+settings-items-list = Items list
+settings-density = Density
+density-comfortable = Comfortable
+density-compact = Compact
+settings-default-order = Default order
+sort-name-ascending = Name A–Z
+sort-name-descending = Name Z–A
+settings-recent = Recently deleted
+recent-hidden = Hidden
+settings-size-units = Size units
+size-units-iec = Binary (KiB, MiB)
+size-units-si = Decimal (kB, MB)
+settings-panel-icon = Panel icon
+settings-indicator = Indicator
+indicator-none = None
+indicator-item-count = Item count
+indicator-fill-level = Fill level
+settings-quota = Full at
+settings-middle-click = Middle click
+settings-right-click = Right click
+panel-action-nothing = Nothing
+panel-action-popup = Open popup
+panel-action-context-menu = Show menu
+panel-action-open-trash = Open trash in files
+settings-scroll-recent = Scroll through recent items
+settings-confirmations = Confirmations
+settings-confirm-empty = Ask before emptying the trash
+settings-confirm-delete = Ask before deleting an item
+settings-notifications = Notifications
+settings-notify-empty = Trash emptied
+settings-notify-auto-purge = Automatic cleanup
+settings-notify-restore = Item restored
+settings-notify-restore-failure = Restore failed
+settings-size-threshold = Trash size threshold
+threshold-off = Off
+settings-cleanup = Cleanup
+settings-retention = Delete items after
+retention-never = Never
+retention-days = { $days ->
+    [one] 1 day
+    *[other] { $days } days
+}
+settings-rules-button = { $count ->
+    [one] 1 rule…
+    *[other] { $count } rules…
+}
+settings-deletion = Deletion
+settings-secure-erase = Securely erase when deleting
+settings-passes = Overwrite passes
+passes = { $count ->
+    [one] 1 pass
+    *[other] { $count } passes
+}
+settings-read-only = Open items read-only
+settings-file-manager = File manager
+settings-application = Application
+file-manager-cosmic-files = COSMIC Files
+file-manager-custom = Custom
+
+# Cleanup rules
+cleanup-rules = Cleanup rules
+rules-empty = No rules yet
+rule-remove = Remove rule
+rule-add = Add rule
+rule-pattern-empty = Pattern is empty
+syntax-glob = Glob
+syntax-regex = Regex
+target-name = Name
+target-location = Location
+action-keep = Never auto-purge
+action-purge-immediately = Purge immediately
+
+# Error banner
+show-conflicting-file = Show conflicting file
+retry = Retry
+undo = Undo
+dismiss = Dismiss
+dismiss-all = Dismiss all
+open-location = Open location
+trash-partially-emptied = Trash partially emptied
+failed-empty = Failed to empty the trash
+failed-restore = { $count ->
+    [0] Failed to restore
+    [one] Failed to restore { $name }
+    *[other] Failed to restore { $count } items
+}
+failed-delete = { $count ->
+    [0] Failed to delete
+    [one] Failed to delete { $name }
+    *[other] Failed to delete { $count } items
+}
+failed-open = { $count ->
+    [0] Failed to open
+    [one] Failed to open { $name }
+    *[other] Failed to open { $count } items
+}
+failed-file-manager = Failed to open the file manager
+failed-export = Failed to export the list
+failed-cleanup = { $count ->
+    [0] Failed to clean up the trash
+    [one] Failed to clean up { $name }
+    *[other] Failed to clean up { $count } items
+}
+
+# Notifications
+notify-emptied = Trash emptied
+notify-emptied-body = { $count ->
+    [one] 1 item was permanently deleted
+    *[other] { $count } items were permanently deleted
+}
+notify-auto-purged = Trash cleaned up
+notify-auto-purged-body = { $count ->
+    [one] 1 item was permanently deleted by cleanup rules
+    *[other] { $count } items were permanently deleted by cleanup rules
+}
+notify-restored = Restored { $name }
+notify-restore-failed = Could not restore { $name }
+notify-size-threshold = Trash is getting large
+notify-size-threshold-body = The trash now uses { $size }
+
+# Errors
+error-permission-denied = Permission denied
+error-permission-denied-path = Permission denied: { $path }
+error-not-found = No longer in the trash
+error-not-found-path = No longer exists: { $path }
+error-conflict = Something already exists at { $path }
+error-cross-device = Cannot move across file systems
+error-cross-device-path = Cannot move across file systems: { $path }
+error-partial-failure = { $count ->
+    [1] 1 item could not be deleted
+    *[other] { $count } items could not be deleted
+}
+
+# Accessibility (screen reader names and descriptions)
+a11y-panel-status = { $count ->
+    [0] Trash is empty
+    [1] 1 item in the trash
+    *[other] { $count } items in the trash
+}
+a11y-panel-fill = { $percent }% of the quota used
+a11y-item = { $name }, { $size }
+a11y-item-description = Deleted from { $location }. Activate to show a preview.
+a11y-open-item = Open { $name }
+a11y-reveal-item = Show original location of { $name }
+a11y-restore-item = Restore { $name }
+a11y-delete-item = Delete { $name } permanently
+a11y-sort-ascending = Sort by name, currently A to Z
+a11y-sort-descending = Sort by name, currently Z to A
+a11y-back = Back
+a11y-remove-rule = Remove rule { $pattern }
+a11y-show-conflict = Show conflicting file { $path }
+a11y-retry = Retry: { $operation }
+a11y-dismiss-error = Dismiss error: { $operation }
+a11y-open-failed-location = Open location of { $name }
+a11y-retry-failed-item = Retry { $name }
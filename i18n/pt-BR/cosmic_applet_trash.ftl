@@ -0,0 +1,279 @@
+# Trash applet strings
+
+trash = Lixeira
+open-trash = Abrir lixeira no gerenciador de arquivos…
+open-trash-action = Abrir lixeira
+empty-trash = Esvaziar lixeira
+restore-items = Restaurar itens
+items-count = { $count ->
+    [0] Vazia
+    [1] 1 item
+    *[other] { $count } itens
+}
+
+# Sizes (value is already formatted for the locale)
+size-bytes = { $value } B
+size-kib = { $value } KiB
+size-mib = { $value } MiB
+size-gib = { $value } GiB
+size-tib = { $value } TiB
+size-kb = { $value } kB
+size-mb = { $value } MB
+size-gb = { $value } GB
+size-tb = { $value } TB
+folder-items = { $count ->
+    [one] 1 item
+    *[other] { $count } itens
+}
+
+# Durations
+duration-less-than-minute = menos de um minuto
+duration-minutes = { $count ->
+    [one] 1 minuto
+    *[other] { $count } minutos
+}
+duration-hours = { $count ->
+    [one] 1 hora
+    *[other] { $count } horas
+}
+duration-days = { $count ->
+    [one] 1 dia
+    *[other] { $count } dias
+}
+
+# Panel tooltip
+tooltip-contents = { $count ->
+    [one] 1 item
+    *[other] { $count } itens
+} · { $size }
+tooltip-oldest = Item mais antigo excluído há { $age }
+tooltip-next-cleanup = Próxima limpeza em { $duration }
+tooltip-next-cleanup-soon = Próxima limpeza em menos de uma hora
+tooltip-recent = Recente { $position }/{ $count }: { $name }, excluído há { $age }
+
+# Popup
+recently-deleted = Excluídos recentemente
+deleted-ago = há { $age }
+search-trash = Pesquisar na lixeira
+search-no-match = Nenhum item corresponde à pesquisa
+trash-is-empty = A lixeira está vazia
+empty-trash-menu = Esvaziar lixeira…
+empty-trash-except-pinned = Esvaziar tudo exceto fixados…
+secure-empty-trash = Esvaziar lixeira com segurança…
+secure-erase-progress = Apagando { $name } com segurança… { $percent }%
+secure-erase-copy-on-write = { $filesystem } usa cópia na escrita: o conteúdo antigo pode permanecer no disco
+secure-erase-limitations = A sobrescrita não é confiável em SSDs ou sistemas de arquivos com cópia na escrita (Btrfs, ZFS): o conteúdo antigo pode sobreviver em outra parte do disco.
+export-list = Exportar lista…
+export-dialog-title = Exportar lista da lixeira
+show-restored = Mostrar item restaurado…
+settings-menu = Configurações…
+marked-count = { $count ->
+    [one] 1 selecionado
+    *[other] { $count } selecionados
+}
+clear = Limpar
+cancel = Cancelar
+cannot-undo = Esta ação não pode ser desfeita.
+confirm-empty = { $count ->
+    [one] Excluir permanentemente 1 item da lixeira?
+    *[other] Excluir permanentemente { $count } itens da lixeira?
+}
+confirm-empty-action = Esvaziar lixeira
+confirm-delete = Excluir { $name } permanentemente?
+confirm-delete-marked = { $count ->
+    [one] Excluir 1 item permanentemente?
+    *[other] Excluir { $count } itens permanentemente?
+}
+confirm-add-rule = { $count ->
+    [one] Esta regra exclui permanentemente 1 item que já está na lixeira. Adicioná-la?
+    *[other] Esta regra exclui permanentemente { $count } itens que já estão na lixeira. Adicioná-la?
+}
+confirm-remove-rule = { $count ->
+    [one] Sem esta regra, 1 item que já está na lixeira é excluído permanentemente. Removê-la?
+    *[other] Sem esta regra, { $count } itens que já estão na lixeira são excluídos permanentemente. Removê-la?
+}
+confirm-change-setting = { $count ->
+    [one] Isso exclui permanentemente 1 item que já está na lixeira. Alterar a configuração?
+    *[other] Isso exclui permanentemente { $count } itens que já estão na lixeira. Alterar a configuração?
+}
+confirm-change-setting-action = Alterar configuração
+
+# Items list
+list-files = Arquivos
+list-actions = Ações
+action-open = Abrir
+action-reveal = Mostrar local original
+action-restore = Restaurar
+action-delete = Excluir
+pin = Fixar
+unpin = Desafixar
+pin-description = Itens fixados são mantidos ao esvaziar a lixeira
+secure-delete = Excluir com segurança
+
+# Preview
+preview-loading = Carregando pré-visualização…
+preview-more = { $count ->
+    [one] … e mais 1
+    *[other] … e mais { $count }
+}
+preview-original-location = Local original
+preview-permissions = Permissões
+preview-modified = Modificado
+
+# Settings
+settings = Configurações
+settings-items-list = Lista de itens
+settings-density = Densidade
+density-comfortable = Confortável
+density-compact = Compacta
+settings-default-order = Ordem padrão
+sort-name-ascending = Nome A–Z
+sort-name-descending = Nome Z–A
+settings-recent = Excluídos recentemente
+recent-hidden = Oculto
+settings-size-units = Unidades de tamanho
+size-units-iec = Binário (KiB, MiB)
+size-units-si = Decimal (kB, MB)
+settings-panel-icon = Ícone do painel
+settings-indicator = Indicador
+indicator-none = Nenhum
+indicator-item-count = Número de itens
+indicator-fill-level = Nível de ocupação
+settings-quota = Cheia em
+settings-middle-click = Clique do meio
+settings-right-click = Clique direito
+panel-action-nothing = Nada
+panel-action-popup = Abrir popup
+panel-action-context-menu = Mostrar menu
+panel-action-open-trash = Abrir lixeira no gerenciador de arquivos
+settings-scroll-recent = Rolar pelos itens recentes
+settings-confirmations = Confirmações
+settings-confirm-empty = Perguntar antes de esvaziar a lixeira
+settings-confirm-delete = Perguntar antes de excluir um item
+settings-notifications = Notificações
+settings-notify-empty = Lixeira esvaziada
+settings-notify-auto-purge = Limpeza automática
+settings-notify-restore = Item restaurado
+settings-notify-restore-failure = Falha ao restaurar
+settings-size-threshold = Limite de tamanho da lixeira
+threshold-off = Desligado
+settings-cleanup = Limpeza
+settings-retention = Excluir itens após
+retention-never = Nunca
+retention-days = { $days ->
+    [one] 1 dia
+    *[other] { $days } dias
+}
+settings-rules-button = { $count ->
+    [one] 1 regra…
+    *[other] { $count } regras…
+}
+settings-deletion = Exclusão
+settings-secure-erase = Apagar com segurança ao excluir
+settings-passes = Passagens de sobrescrita
+passes = { $count ->
+    [one] 1 passagem
+    *[other] { $count } passagens
+}
+settings-read-only = Abrir itens somente leitura
+settings-file-manager = Gerenciador de arquivos
+settings-application = Aplicativo
+file-manager-cosmic-files = Arquivos do COSMIC
+file-manager-custom = Personalizado
+
+# Cleanup rules
+cleanup-rules = Regras de limpeza
+rules-empty = Nenhuma regra ainda
+rule-remove = Remover regra
+rule-add = Adicionar regra
+rule-pattern-empty = O padrão está vazio
+syntax-glob = Glob
+syntax-regex = Regex
+target-name = Nome
+target-location = Local
+action-keep = Nunca limpar automaticamente
+action-purge-immediately = Excluir imediatamente
+
+# Error banner
+show-conflicting-file = Mostrar arquivo conflitante
+retry = Tentar novamente
+undo = Desfazer
+dismiss = Dispensar
+dismiss-all = Dispensar todos
+open-location = Abrir local
+trash-partially-emptied = Lixeira esvaziada parcialmente
+failed-empty = Falha ao esvaziar a lixeira
+failed-restore = { $count ->
+    [0] Falha ao restaurar
+    [one] Falha ao restaurar { $name }
+    *[other] Falha ao restaurar { $count } itens
+}
+failed-delete = { $count ->
+    [0] Falha ao excluir
+    [one] Falha ao excluir { $name }
+    *[other] Falha ao excluir { $count } itens
+}
+failed-open = { $count ->
+    [0] Falha ao abrir
+    [one] Falha ao abrir { $name }
+    *[other] Falha ao abrir { $count } itens
+}
+failed-file-manager = Falha ao abrir o gerenciador de arquivos
+failed-export = Falha ao exportar a lista
+failed-cleanup = { $count ->
+    [0] Falha ao limpar a lixeira
+    [one] Falha ao limpar { $name }
+    *[other] Falha ao limpar { $count } itens
+}
+
+# Notifications
+notify-emptied = Lixeira esvaziada
+notify-emptied-body = { $count ->
+    [one] 1 item foi excluído permanentemente
+    *[other] { $count } itens foram excluídos permanentemente
+}
+notify-auto-purged = Lixeira limpa
+notify-auto-purged-body = { $count ->
+    [one] 1 item foi excluído permanentemente pelas regras de limpeza
+    *[other] { $count } itens foram excluídos permanentemente pelas regras de limpeza
+}
+notify-restored = { $name } restaurado
+notify-restore-failed = Não foi possível restaurar { $name }
+notify-size-threshold = A lixeira está ficando grande
+notify-size-threshold-body = A lixeira agora ocupa { $size }
+
+# Errors
+error-permission-denied = Permissão negada
+error-permission-denied-path = Permissão negada: { $path }
+error-not-found = Não está mais na lixeira
+error-not-found-path = Não existe mais: { $path }
+error-conflict = Já existe algo em { $path }
+error-cross-device = Não é possível mover entre sistemas de arquivos
+error-cross-device-path = Não é possível mover entre sistemas de arquivos: { $path }
+error-partial-failure = { $count ->
+    [1] 1 item não pôde ser excluído
+    *[other] { $count } itens não puderam ser excluídos
+}
+
+# Accessibility (screen reader names and descriptions)
+a11y-panel-status = { $count ->
+    [0] A lixeira está vazia
+    [1] 1 item na lixeira
+    *[other] { $count } itens na lixeira
+}
+a11y-panel-fill = { $percent }% da cota usada
+a11y-item = { $name }, { $size }
+a11y-item-description = Excluído de { $location }. Ative para ver uma pré-visualização.
+a11y-open-item = Abrir { $name }
+a11y-reveal-item = Mostrar local original de { $name }
+a11y-restore-item = Restaurar { $name }
+a11y-delete-item = Excluir { $name } permanentemente
+a11y-sort-ascending = Ordenar por nome, atualmente de A a Z
+a11y-sort-descending = Ordenar por nome, atualmente de Z a A
+a11y-back = Voltar
+a11y-remove-rule = Remover regra { $pattern }
+a11y-show-conflict = Mostrar arquivo conflitante { $path }
+a11y-retry = Tentar novamente: { $operation }
+a11y-dismiss-error = Dispensar erro: { $operation }
+a11y-open-failed-location = Abrir local de { $name }
+a11y-retry-failed-item = Tentar novamente: { $name }
@@ -1,32 +1,171 @@
 // SPDX-License-Identifier: GPL-3.0-only
 
-use crate::config::Config;
+use crate::cleanup_rules::{CleanupPolicy, CleanupRule, PatternSyntax, RuleAction, RuleTarget};
+use crate::config::{self, Config, PanelAction, SettingChange};
+use crate::error::TrashError;
+use crate::fl;
+use crate::notifications::{NotificationAction, NotificationEvent};
+use crate::operation_error::{self, Operation, OperationError};
+use crate::secure_erase::SecureEraseProgress;
+use crate::size_format::SizeUnits;
+use crate::trash_export::{self, ExportFormat};
 use crate::trash_item_metadata::EnrichedTrashItem;
+use crate::trash_preview::TrashPreview;
 use crate::trash_status::TrashStatus;
-use crate::{file_manager, trash_operations, ui_panel_button, ui_popup};
+use crate::{
+    dbus_service, default_app, file_manager, notifications, trash_operations, trash_preview,
+    ui_items, ui_panel_button, ui_popup, ui_rules, ui_settings,
+};
 use cosmic::cosmic_config::{self, CosmicConfigEntry};
+use cosmic::iced::keyboard::{self, Key, Modifiers, key::Named};
 use cosmic::iced::{Limits, Subscription, window::Id};
 use cosmic::iced_futures::stream;
 use cosmic::iced_winit::commands::popup::{destroy_popup, get_popup};
 use cosmic::prelude::*;
+use cosmic::widget;
+use futures_util::{SinkExt, StreamExt};
 use notify_debouncer_full::{DebounceEventResult, new_debouncer, notify};
 use std::any::TypeId;
 use std::time::Duration;
 
+/// How often retention is re-checked while the trash is unchanged
+const CLEANUP_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// Recent items the panel tooltip cycles through when scrolling
+const TOOLTIP_RECENT: usize = 10;
+
+/// Notifications whose actions this instance still answers (most recent)
+const MAX_NOTIFICATION_IDS: usize = 32;
+
 /// `AppModel`: Application state and message orchestrator
 pub struct AppModel {
     core: cosmic::Core,
     popup: Option<Id>,
+    popup_page: PopupPage,
+    config_handler: Option<cosmic_config::Config>, // Writes (pinned items)
     config: Config,
 
     // Trash state (reactive)
     trash_status: TrashStatus,
     trash_items: Vec<EnrichedTrashItem>,
+    trash_size: u64, // Total bytes of all items (size threshold, panel fill level)
+    trash_size_known: bool, // False until the first load (startup size is not a "crossing")
     sort_ascending: bool, // true = A-Z, false = Z-A (folders always first)
+    recent_index: Option<usize>, // Recent item shown in the panel tooltip (scrolling)
+
+    // Quick-look preview (selected row, by trash id)
+    selected_item: Option<std::ffi::OsString>,
+    preview: Option<TrashPreview>,
+
+    // Keyboard navigation (see `keyboard_shortcut`)
+    focused_item: Option<std::ffi::OsString>, // Row with the focus ring
+    marked_items: Vec<std::ffi::OsString>,    // Multi-selection (Ctrl+A)
+    search_query: String,                     // Filters the list by name/location
+
+    // Last successfully restored item (offered as "Show restored item")
+    last_restored: Option<std::path::PathBuf>,
 
     // Operation state
+    pending_confirmation: Option<Confirmation>,
     empty_in_progress: bool,
-    operation_error: Option<String>,
+    secure_erase_progress: Option<SecureEraseProgress>, // Running shred (progress bar)
+    operation_errors: Vec<OperationError>,              // Recent errors (banner), oldest first
+
+    // Automatic cleanup (retention + rules)
+    auto_purge_in_progress: bool,
+    auto_purge_failed: Vec<std::ffi::OsString>, // Skipped until the next periodic check
+    next_cleanup: Option<i64>, // Next retention expiry (Unix seconds, panel tooltip)
+    rule_draft: CleanupRule,   // "Add rule" form
+    rule_draft_error: Option<String>, // Invalid pattern message
+
+    // Custom file manager command being typed (saved on Enter or leaving Settings)
+    custom_file_manager_draft: Option<String>,
+
+    // Session-bus service (kept alive while the applet runs)
+    dbus_connection: Option<zbus::Connection>,
+
+    // Ids of notifications sent by this instance (actions on others are ignored)
+    notification_ids: Vec<u32>,
+}
+
+/// Page shown in the popup
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PopupPage {
+    #[default]
+    Main,
+    Settings,
+    CleanupRules,
+    /// Compact right-click menu (see `Config::right_click`)
+    ContextMenu,
+}
+
+/// Permanent deletion waiting for the user's confirmation
+#[derive(Debug, Clone)]
+pub enum Confirmation {
+    Empty {
+        secure: bool,
+    },
+    Delete {
+        item: EnrichedTrashItem,
+        secure: bool,
+    },
+    /// Several marked items at once
+    DeleteMarked {
+        items: Vec<EnrichedTrashItem>,
+    },
+    /// New "purge immediately" rule matching `matched` items already in the trash
+    AddRule {
+        rule: CleanupRule,
+        matched: usize,
+    },
+    /// Removing a rule that keeps `matched` items cleanup would otherwise purge
+    RemoveRule {
+        index: usize,
+        matched: usize,
+    },
+    /// Setting (retention) that makes cleanup purge `matched` items right away
+    ChangeSetting {
+        change: SettingChange,
+        matched: usize,
+    },
+}
+
+impl Confirmation {
+    /// Message that performs the confirmed action
+    fn into_message(self) -> Message {
+        match self {
+            Self::Empty { secure: false } => Message::EmptyTrash,
+            Self::Empty { secure: true } => Message::SecureEmptyTrash,
+            Self::Delete {
+                item,
+                secure: false,
+            } => Message::DeleteItem(item),
+            Self::Delete { item, secure: true } => Message::SecureDeleteItem(item),
+            Self::DeleteMarked { items } => Message::DeleteItems(items),
+            Self::AddRule { rule, .. } => Message::SaveRule(rule),
+            Self::RemoveRule { index, .. } => Message::SaveRuleRemoval(index),
+            Self::ChangeSetting { change, .. } => Message::SaveSetting(change),
+        }
+    }
+}
+
+/// Popup keyboard shortcuts (see `keyboard_shortcut`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyboardAction {
+    /// ↓: focus the next row
+    FocusNext,
+    /// ↑: focus the previous row
+    FocusPrevious,
+    /// Enter: restore the marked items, or the focused one
+    Restore,
+    /// Shift+Delete: permanently delete the marked items, or the focused one
+    Purge,
+    /// Ctrl+A: mark every visible row (again: unmark all)
+    SelectAll,
+    /// `/`: focus the search field
+    FocusSearch,
+    /// Escape: cancel a prompt, leave a sub page, or close the popup
+    Close,
 }
 
 /// Applet messages
@@ -36,26 +175,97 @@ pub enum Message {
     // Popup
     TogglePopup,
     PopupClosed(Id),
+    ShowPage(PopupPage),
+
+    // Panel icon mouse actions (see `Config::middle_click` / `right_click`)
+    PanelAction(PanelAction),
+    CycleRecent(i32), // Scroll: +1 older, -1 newer recent item in the tooltip
+    PanelExit,        // Pointer left the icon: tooltip back to the summary
 
     // Configuration
     UpdateConfig(Config),
 
+    // D-Bus service
+    DbusServiceReady(zbus::Connection),
+
+    // Desktop notifications
+    NotificationSent(Result<u32, String>), // Notification id
+    NotificationAction(u32, NotificationAction), // Notification id, action
+    UndoRestoreComplete(std::path::PathBuf, Result<(), TrashError>), // Back in the trash
+
+    // Settings
+    ChangeSetting(SettingChange),
+    SaveSetting(SettingChange), // Confirmed if it purges existing items
+    CustomFileManagerDraft(String),
+    SaveCustomFileManager,
+
+    // Confirmation prompts (permanent deletion)
+    Confirm(Confirmation), // Asks first if enabled in Config, otherwise runs it
+    ConfirmPending,
+    CancelPending,
+
     // Trash (Backend)
     TrashStatusChanged(TrashStatus),
-    TrashItemsLoaded(Vec<trash::TrashItem>),
+    TrashItemsLoaded(Vec<EnrichedTrashItem>),
 
     EmptyTrash,
-    EmptyTrashComplete(Result<(), String>),
+    EmptyTrashComplete(Result<(), TrashError>),
 
     RestoreItem(EnrichedTrashItem),
-    RestoreComplete(Result<std::path::PathBuf, String>),
+    RestoreComplete(EnrichedTrashItem, Result<std::path::PathBuf, TrashError>),
 
     DeleteItem(EnrichedTrashItem),
-    DeleteComplete(Result<(), String>),
+    DeleteComplete(EnrichedTrashItem, Result<(), TrashError>),
+
+    // Marked items (multi-selection)
+    RestoreItems(Vec<EnrichedTrashItem>),
+    DeleteItems(Vec<EnrichedTrashItem>),
+    DeleteItemsComplete(Vec<EnrichedTrashItem>, Result<(), TrashError>),
+    ClearMarked,
+
+    TogglePin(EnrichedTrashItem), // Pinned items are skipped by "Empty trash" and cleanup
+
+    // Automatic cleanup
+    RunCleanup, // Periodic retention check (reloads the list)
+    AutoPurgeComplete(Vec<EnrichedTrashItem>, Result<(), TrashError>),
+    RuleDraftPattern(String),
+    RuleDraftTarget(usize),
+    RuleDraftSyntax(usize),
+    RuleDraftAction(usize),
+    AddRule,
+    SaveRule(CleanupRule), // Validated (and confirmed if it purges existing items)
+    RemoveRule(usize),
+    SaveRuleRemoval(usize), // Confirmed if it lets cleanup purge existing items
+
+    // Secure erase (overwrite before unlinking)
+    SecureDeleteItem(EnrichedTrashItem),
+    SecureEmptyTrash,
+    SecureEraseProgress(SecureEraseProgress),
+
+    // Error banner
+    RetryOperation(usize),
+    RetryFailedItem(usize, usize), // (error index, item index) within a partial failure
+    DismissError(usize),
+    DismissAllErrors,
+
+    OpenItem(EnrichedTrashItem), // Open in default app without restoring
+    OpenComplete(EnrichedTrashItem, Result<(), String>),
+    FileManagerComplete(Result<(), String>),
+
+    SelectItem(EnrichedTrashItem), // Toggle inline preview for a row
+    PreviewLoaded(TrashPreview),
+
+    // Keyboard navigation and search
+    Keyboard(KeyboardAction),
+    SearchChanged(String),
+
+    ExportList, // Save dialog → JSON/CSV export
+    ExportComplete(Result<Option<std::path::PathBuf>, String>),
 
     OpenTrashFolder,
-    ToggleSortOrder,                  // Toggle sort order A-Z ↔ Z-A
-    Surface(cosmic::surface::Action), // For applet_tooltip
+    RevealOriginal(std::path::PathBuf), // Show original location in the file manager
+    ToggleSortOrder,                    // Toggle sort order A-Z ↔ Z-A
+    Surface(cosmic::surface::Action),   // For applet_tooltip
 
     // [PHASE 2+] Drag &amp; Drop (foundation)
     DndUriReceived(String),
@@ -94,26 +304,51 @@ impl cosmic::Application for AppModel {
 
         // Load trash items immediately if not empty
         if !trash_status.is_empty {
-            commands.push(Task::perform(trash_operations::list_items(), |result| {
-                cosmic::Action::App(Message::TrashItemsLoaded(result.unwrap_or_default()))
-            }));
+            commands.push(load_items());
         }
 
-        let config = cosmic_config::Config::new(Self::APP_ID, Config::VERSION)
-            .map(|context| match Config::get_entry(&context) {
+        let config_handler = cosmic_config::Config::new(Self::APP_ID, Config::VERSION).ok();
+        let config = config_handler
+            .as_ref()
+            .map(|context| match Config::get_entry(context) {
                 Ok(config) | Err((_, config)) => config,
             })
             .unwrap_or_default();
 
+        let sort_ascending = config.default_sort.is_ascending();
+        // An empty trash has a known size; otherwise wait for the first load
+        let trash_size_known = trash_status.is_empty;
+
         let app = AppModel {
             core,
             popup: None,
+            popup_page: PopupPage::Main,
+            config_handler,
             config,
             trash_status,
             trash_items: Vec::new(),
-            sort_ascending: true, // Default A-Z ascending order
+            trash_size: 0,
+            trash_size_known,
+            sort_ascending,
+            recent_index: None,
+            selected_item: None,
+            preview: None,
+            focused_item: None,
+            marked_items: Vec::new(),
+            search_query: String::new(),
+            last_restored: None,
+            pending_confirmation: None,
             empty_in_progress: false,
-            operation_error: None,
+            secure_erase_progress: None,
+            operation_errors: Vec::new(),
+            auto_purge_in_progress: false,
+            auto_purge_failed: Vec::new(),
+            next_cleanup: None,
+            rule_draft: CleanupRule::default(),
+            rule_draft_error: None,
+            custom_file_manager_draft: None,
+            dbus_connection: None,
+            notification_ids: Vec::new(),
         };
 
         (app, Task::batch(commands))
@@ -125,15 +360,82 @@ impl cosmic::Application for AppModel {
 
     /// Ícone do painel (estado reativo)
     fn view(&self) -> Element<'_, Self::Message> {
-        ui_panel_button::view(&self.trash_status, &self.core)
+        ui_panel_button::view(
+            &self.trash_status,
+            self.trash_size,
+            self.next_cleanup,
+            &EnrichedTrashItem::most_recent(&self.trash_items, TOOLTIP_RECENT),
+            self.recent_index,
+            &self.config,
+            &self.core,
+        )
     }
 
     /// Popup window
     fn view_window(&self, _id: Id) -> Element<'_, Self::Message> {
+        match self.popup_page {
+            PopupPage::Main => {}
+            PopupPage::ContextMenu => {
+                return ui_popup::context_menu(
+                    &self.trash_status,
+                    &self.trash_items,
+                    &self.config,
+                    &self.core,
+                );
+            }
+            PopupPage::Settings => {
+                return ui_popup::page(
+                    fl!("settings"),
+                    PopupPage::Main,
+                    ui_settings::view(
+                        &self.config,
+                        self.custom_file_manager_draft.as_deref(),
+                        self.pending_confirmation.as_ref().map(|confirmation| {
+                            ui_popup::confirmation_card(
+                                confirmation,
+                                &self.trash_items,
+                                &self.config.pinned_items,
+                            )
+                        }),
+                    ),
+                    &self.core,
+                );
+            }
+            PopupPage::CleanupRules => {
+                return ui_popup::page(
+                    fl!("cleanup-rules"),
+                    PopupPage::Settings,
+                    ui_rules::view(
+                        &self.config.cleanup_rules,
+                        &self.rule_draft,
+                        self.rule_draft_error.as_deref(),
+                        self.pending_confirmation.as_ref().map(|confirmation| {
+                            ui_popup::confirmation_card(
+                                confirmation,
+                                &self.trash_items,
+                                &self.config.pinned_items,
+                            )
+                        }),
+                    ),
+                    &self.core,
+                );
+            }
+        }
+
         ui_popup::view(
             &self.trash_status,
             &self.trash_items,
             self.sort_ascending,
+            self.selected_item.as_ref(),
+            self.focused_item.as_ref(),
+            &self.marked_items,
+            &self.search_query,
+            self.preview.as_ref(),
+            self.last_restored.as_deref(),
+            &self.operation_errors,
+            &self.config,
+            self.pending_confirmation.as_ref(),
+            self.secure_erase_progress.as_ref(),
             &self.core,
         )
     }
@@ -199,48 +501,421 @@ impl cosmic::Application for AppModel {
             }),
         );
 
-        Subscription::batch(vec![
+        struct DbusServiceSubscription;
+
+        let dbus_subscription = Subscription::run_with_id(
+            TypeId::of::<DbusServiceSubscription>(),
+            stream::channel(1, |mut output| {
+                #[allow(clippy::semicolon_if_nothing_returned)]
+                async move {
+                    match dbus_service::serve().await {
+                        Ok(connection) => {
+                            if let Err(e) = output.send(Message::DbusServiceReady(connection)).await
+                            {
+                                eprintln!("Failed to send D-Bus connection: {e:?}");
+                            }
+                        }
+                        Err(e) => {
+                            eprintln!("Failed to start D-Bus service: {e}");
+                        }
+                    }
+
+                    std::future::pending().await
+                }
+            }),
+        );
+
+        struct NotificationActionsSubscription;
+
+        let notification_subscription = Subscription::run_with_id(
+            TypeId::of::<NotificationActionsSubscription>(),
+            stream::channel(4, |mut output| {
+                #[allow(clippy::semicolon_if_nothing_returned)]
+                async move {
+                    match notifications::actions().await {
+                        Ok(actions) => {
+                            let mut actions = std::pin::pin!(actions);
+                            while let Some((id, action)) = actions.next().await {
+                                if let Err(e) =
+                                    output.send(Message::NotificationAction(id, action)).await
+                                {
+                                    eprintln!("Failed to send notification action: {e:?}");
+                                }
+                            }
+                        }
+                        Err(e) => {
+                            eprintln!("Failed to listen for notification actions: {e}");
+                        }
+                    }
+
+                    std::future::pending().await
+                }
+            }),
+        );
+
+        let mut subscriptions = vec![
             self.core()
                 .watch_config::<Config>(Self::APP_ID)
                 .map(|update| Message::UpdateConfig(update.config)),
             watcher_subscription,
-        ])
+            dbus_subscription,
+            notification_subscription,
+        ];
+
+        // Retention: items expire without any trash change, so check periodically
+        if self.config.retention_days > 0 {
+            subscriptions
+                .push(cosmic::iced::time::every(CLEANUP_INTERVAL).map(|_| Message::RunCleanup));
+        }
+
+        // Keyboard navigation while the popup is open
+        if self.popup.is_some() {
+            subscriptions.push(keyboard::on_key_press(keyboard_shortcut));
+        }
+
+        Subscription::batch(subscriptions)
     }
 
     #[allow(clippy::too_many_lines)]
     fn update(&mut self, message: Self::Message) -> Task<cosmic::Action<Self::Message>> {
         match message {
             Message::UpdateConfig(config) => {
-                self.config = config;
+                return self.apply_config(config);
+            }
+
+            Message::ChangeSetting(change) => {
+                // Cleanup settings apply to what is already in the trash
+                let mut config = self.config.clone();
+                config.apply(change.clone());
+                let matched = self.purged_by_config(&config);
+                if matched > 0 {
+                    return self.update(Message::Confirm(Confirmation::ChangeSetting {
+                        change,
+                        matched,
+                    }));
+                }
+                return self.update(Message::SaveSetting(change));
+            }
+
+            Message::SaveSetting(change) => {
+                let mut config = self.config.clone();
+                config.apply(change);
+                if let Some(handler) = &self.config_handler
+                    && let Err(e) = config.write_entry(handler)
+                {
+                    eprintln!("Failed to save settings: {e}");
+                }
+                return self.apply_config(config);
+            }
+
+            Message::CustomFileManagerDraft(template) => {
+                self.custom_file_manager_draft = Some(template);
+            }
+
+            Message::SaveCustomFileManager => {
+                return self.save_custom_file_manager();
+            }
+
+            Message::Confirm(confirmation) => {
+                let ask = match &confirmation {
+                    Confirmation::Empty { .. } => self.config.confirm_empty,
+                    Confirmation::Delete { .. } | Confirmation::DeleteMarked { .. } => {
+                        self.config.confirm_delete
+                    }
+                    // Bulk deletion the user did not ask for directly
+                    Confirmation::AddRule { .. }
+                    | Confirmation::RemoveRule { .. }
+                    | Confirmation::ChangeSetting { .. } => true,
+                };
+                if ask {
+                    // From the context menu: continue on the main page (confirmation card)
+                    if self.popup_page == PopupPage::ContextMenu {
+                        self.popup_page = PopupPage::Main;
+                    }
+                    self.pending_confirmation = Some(confirmation);
+                    return Task::none();
+                }
+                return self.update(confirmation.into_message());
+            }
+
+            Message::ConfirmPending => {
+                if let Some(confirmation) = self.pending_confirmation.take() {
+                    return self.update(confirmation.into_message());
+                }
+            }
+
+            Message::CancelPending => {
+                self.pending_confirmation = None;
+            }
+
+            Message::DbusServiceReady(connection) => {
+                self.dbus_connection = Some(connection);
             }
 
             Message::TrashStatusChanged(status) => {
-                self.trash_status = status;
+                self.trash_status = status.clone();
 
                 // Always reload list to ensure correct metadata/icons/ordering
                 // This fixes: wrong icons, missing sizes, incorrect folder sorting for new items
-                return Task::perform(trash_operations::list_items(), |result| {
-                    Message::TrashItemsLoaded(result.unwrap_or_default())
+                let reload = load_items();
+
+                // Notify D-Bus clients (StatusChanged signal)
+                let Some(connection) = self.dbus_connection.clone() else {
+                    return reload;
+                };
+                let signal = Task::future(async move {
+                    if let Err(e) = dbus_service::emit_status_changed(connection, status).await {
+                        eprintln!("Failed to emit StatusChanged: {e}");
+                    }
                 })
-                .map(cosmic::Action::App);
+                .discard();
+
+                return Task::batch([reload, signal]);
             }
 
-            Message::TrashItemsLoaded(items) => {
-                // Enrich items with pre-computed metadata (size, icon)
-                let mut enriched_items: Vec<EnrichedTrashItem> = items
-                    .into_iter()
-                    .map(EnrichedTrashItem::from_trash_item)
-                    .collect();
+            Message::TrashItemsLoaded(mut enriched_items) => {
+                // Automatic cleanup: purge what retention/rules say is due
+                let cleanup_task = self.auto_purge(&enriched_items);
+                self.next_cleanup = CleanupPolicy::from_config(&self.config)
+                    .next_due(enriched_items.iter().map(|enriched| &enriched.item));
 
                 // Sort: folders first (alphabetical), then files (alphabetical)
                 EnrichedTrashItem::sort_items(&mut enriched_items, self.sort_ascending);
 
                 self.trash_items = enriched_items;
+                self.recent_index = self
+                    .recent_index
+                    .filter(|index| *index < self.trash_items.len().min(TOOLTIP_RECENT));
+
+                // Size threshold: notify once when crossing it (not on every reload)
+                let previous_size = self.trash_size_known.then_some(self.trash_size);
+                self.trash_size_known = true;
+                self.trash_size = self
+                    .trash_items
+                    .iter()
+                    .filter_map(|enriched| enriched.size_bytes)
+                    .sum();
+                let threshold = self.config.size_threshold_mb.saturating_mul(1024 * 1024);
+                let size_task = if threshold > 0
+                    && previous_size.is_some_and(|previous| previous < threshold)
+                    && self.trash_size >= threshold
+                {
+                    notify(NotificationEvent::SizeThreshold {
+                        total_bytes: self.trash_size,
+                        units: self.config.size_units,
+                    })
+                } else {
+                    Task::none()
+                };
+
+                // Drop the selection if the item left the trash (restored/deleted)
+                if let Some(id) = &self.selected_item
+                    && !self
+                        .trash_items
+                        .iter()
+                        .any(|enriched| &enriched.item.id == id)
+                {
+                    self.selected_item = None;
+                    self.preview = None;
+                }
+                let in_trash = |id: &std::ffi::OsString| {
+                    self.trash_items
+                        .iter()
+                        .any(|enriched| &enriched.item.id == id)
+                };
+                if self.focused_item.as_ref().is_some_and(|id| !in_trash(id)) {
+                    self.focused_item = None;
+                }
+                self.marked_items.retain(|id| in_trash(id));
+
+                return Task::batch([cleanup_task, size_task]);
+            }
+
+            Message::NotificationSent(result) => match result {
+                Ok(id) => {
+                    self.notification_ids.push(id);
+                    if self.notification_ids.len() > MAX_NOTIFICATION_IDS {
+                        self.notification_ids.remove(0);
+                    }
+                }
+                Err(e) => eprintln!("Failed to send notification: {e}"),
+            },
+
+            Message::NotificationAction(id, action) => {
+                // Other applet instances (panels) and stale notifications
+                let Some(position) = self.notification_ids.iter().position(|sent| *sent == id)
+                else {
+                    return Task::none();
+                };
+                // Answered once, even if the daemon keeps the notification
+                self.notification_ids.remove(position);
+
+                return match action {
+                    NotificationAction::OpenTrash => self.update(Message::OpenTrashFolder),
+                    NotificationAction::EmptyTrash => {
+                        // Same prompt as the popup button, which needs the popup open
+                        let open = if !self.config.confirm_empty {
+                            Task::none()
+                        } else if self.popup.is_some() {
+                            self.popup_page = PopupPage::Main;
+                            Task::none()
+                        } else {
+                            self.toggle_popup(PopupPage::Main)
+                        };
+                        let confirm =
+                            self.update(Message::Confirm(Confirmation::Empty { secure: false }));
+                        Task::batch([open, confirm])
+                    }
+                    NotificationAction::UndoRestore(path) => Task::perform(
+                        trash_operations::put_items(vec![path.clone()]),
+                        move |result| Message::UndoRestoreComplete(path, result),
+                    )
+                    .map(cosmic::Action::App),
+                    NotificationAction::RetryRestore(id) => {
+                        match self
+                            .trash_items
+                            .iter()
+                            .find(|enriched| enriched.item.id.to_string_lossy() == id.as_str())
+                        {
+                            Some(enriched) => self.update(Message::RestoreItem(enriched.clone())),
+                            None => Task::none(), // Already restored or deleted
+                        }
+                    }
+                };
             }
 
+            Message::UndoRestoreComplete(path, result) => match result {
+                Ok(()) => {
+                    if self.last_restored.as_ref() == Some(&path) {
+                        self.last_restored = None;
+                    }
+                }
+                Err(e) => eprintln!("Failed to move {} back to the trash: {e}", path.display()),
+            },
+
+            Message::OpenItem(enriched_item) => {
+                let Some(path) = enriched_item.trash_path.clone() else {
+                    self.push_error(
+                        Operation::Open,
+                        vec![enriched_item],
+                        TrashError::NotFound { path: None },
+                    );
+                    return Task::none();
+                };
+
+                return Task::perform(
+                    default_app::open_item(
+                        path,
+                        enriched_item.mime.clone(),
+                        self.config.open_read_only,
+                    ),
+                    move |result| Message::OpenComplete(enriched_item, result),
+                )
+                .map(cosmic::Action::App);
+            }
+
+            Message::OpenComplete(enriched_item, result) => {
+                if let Err(e) = result {
+                    self.push_error(Operation::Open, vec![enriched_item], e);
+                }
+            }
+
+            Message::FileManagerComplete(result) => {
+                if let Err(e) = result {
+                    self.push_error(Operation::FileManager, Vec::new(), e);
+                }
+            }
+
+            Message::SelectItem(enriched_item) => {
+                // Clicking the selected row again collapses the preview
+                if self.selected_item.as_ref() == Some(&enriched_item.item.id) {
+                    self.selected_item = None;
+                    self.preview = None;
+                    return Task::none();
+                }
+
+                self.selected_item = Some(enriched_item.item.id.clone());
+                self.preview = None;
+
+                return Task::perform(trash_preview::load(enriched_item), Message::PreviewLoaded)
+                    .map(cosmic::Action::App);
+            }
+
+            Message::PreviewLoaded(preview) => {
+                // Ignore late results for a previous selection
+                if self.selected_item.as_ref() == Some(&preview.id) {
+                    self.preview = Some(preview);
+                }
+            }
+
+            Message::Keyboard(action) => {
+                return self.keyboard(action);
+            }
+
+            Message::SearchChanged(query) => {
+                self.search_query = query;
+                // Keep focus and marks on rows that are still visible
+                let visible: Vec<_> = self
+                    .visible_items()
+                    .map(|enriched| enriched.item.id.clone())
+                    .collect();
+                if self
+                    .focused_item
+                    .as_ref()
+                    .is_some_and(|id| !visible.contains(id))
+                {
+                    self.focused_item = None;
+                }
+                self.marked_items.retain(|id| visible.contains(id));
+            }
+
+            Message::ExportList => {
+                return Task::perform(
+                    export_list(self.trash_items.clone(), self.config.size_units),
+                    Message::ExportComplete,
+                )
+                .map(cosmic::Action::App);
+            }
+
+            Message::ExportComplete(result) => match result {
+                Ok(Some(path)) => {
+                    eprintln!("✅ Exported to: {}", path.display());
+                }
+                Ok(None) => {} // Dialog cancelled
+                Err(e) => {
+                    self.push_error(Operation::Export, Vec::new(), e);
+                }
+            },
+
             Message::OpenTrashFolder => {
-                // Open trash using cosmic-files --trash
-                file_manager::open_trash_folder();
+                // The context menu is done once an entry is chosen
+                let close_menu = if self.popup_page == PopupPage::ContextMenu {
+                    self.popup.take().map_or_else(Task::none, destroy_popup)
+                } else {
+                    Task::none()
+                };
+
+                // Open trash in the configured file manager
+                let open = Task::perform(
+                    file_manager::open_trash_folder(
+                        self.config.file_manager,
+                        self.config.custom_file_manager.clone(),
+                    ),
+                    Message::FileManagerComplete,
+                )
+                .map(cosmic::Action::App);
+                return Task::batch([close_menu, open]);
+            }
+            Message::RevealOriginal(original_path) => {
+                return Task::perform(
+                    file_manager::reveal_original_location(
+                        original_path,
+                        self.config.file_manager,
+                        self.config.custom_file_manager.clone(),
+                    ),
+                    Message::FileManagerComplete,
+                )
+                .map(cosmic::Action::App);
             }
             Message::ToggleSortOrder => {
                 // Toggle sort order (folders always stay first)
@@ -254,101 +929,432 @@ impl cosmic::Application for AppModel {
             }
 
             Message::EmptyTrash => {
+                if self.config.secure_erase {
+                    return self.update(Message::SecureEmptyTrash);
+                }
                 if self.empty_in_progress {
                     return Task::none(); // Prevent multiple clicks
                 }
 
                 self.empty_in_progress = true;
-                self.operation_error = None;
+                // A new attempt supersedes previous empty failures
+                self.operation_errors
+                    .retain(|error| error.operation != Operation::Empty);
 
-                return Task::perform(trash_operations::empty_trash(), |result| {
-                    Message::EmptyTrashComplete(result.map_err(|e| e.to_string()))
-                })
+                return Task::perform(
+                    trash_operations::empty_trash(self.config.pinned_items.clone()),
+                    Message::EmptyTrashComplete,
+                )
                 .map(cosmic::Action::App);
             }
 
+            Message::SecureEmptyTrash => {
+                if self.empty_in_progress {
+                    return Task::none();
+                }
+
+                self.empty_in_progress = true;
+                self.operation_errors
+                    .retain(|error| error.operation != Operation::Empty);
+
+                return secure_erase(
+                    SecureEraseTarget::Trash(self.config.pinned_items.clone()),
+                    self.config.secure_erase_passes,
+                    Message::EmptyTrashComplete,
+                );
+            }
+
             Message::EmptyTrashComplete(result) => {
                 self.empty_in_progress = false;
+                self.secure_erase_progress = None;
 
                 match result {
                     Ok(()) => {
-                        self.trash_items.clear();
+                        // Pinned items stay in the trash
+                        let before = self.trash_items.len();
+                        let pinned = &self.config.pinned_items;
+                        self.trash_items
+                            .retain(|enriched| config::is_pinned(pinned, &enriched.item));
+                        let count = before - self.trash_items.len();
                         // TrashStatusChanged will be sent by watcher
+
+                        if self.config.notify_empty {
+                            return notify(NotificationEvent::TrashEmptied { count });
+                        }
+                    }
+                    Err(TrashError::PartialFailure { failed }) => {
+                        // Drop what was purged; keep the items that failed (and pinned ones)
+                        let is_failed = |enriched: &EnrichedTrashItem| {
+                            failed
+                                .iter()
+                                .any(|failure| failure.item.id == enriched.item.id)
+                        };
+                        let before = self.trash_items.len();
+                        let pinned = &self.config.pinned_items;
+                        self.trash_items.retain(|enriched| {
+                            is_failed(enriched) || config::is_pinned(pinned, &enriched.item)
+                        });
+                        let purged = before - self.trash_items.len();
+                        let failed_items = self
+                            .trash_items
+                            .iter()
+                            .filter(|enriched| is_failed(enriched))
+                            .cloned()
+                            .collect();
+
+                        let task = self.handle_trash_error(
+                            Operation::Empty,
+                            failed_items,
+                            TrashError::PartialFailure { failed },
+                        );
+                        if self.config.notify_empty && purged > 0 {
+                            return Task::batch([
+                                task,
+                                notify(NotificationEvent::TrashEmptied { count: purged }),
+                            ]);
+                        }
+                        return task;
                     }
                     Err(e) => {
-                        self.operation_error = Some(format!("Failed to empty trash: {e}"));
-                        eprintln!("Empty trash error: {e}");
+                        return self.handle_trash_error(Operation::Empty, Vec::new(), e);
                     }
                 }
             }
 
             Message::RestoreItem(enriched_item) => {
                 return Task::perform(
-                    trash_operations::restore_item(enriched_item.item),
-                    |result| Message::RestoreComplete(result.map_err(|e| e.to_string())),
+                    trash_operations::restore_item(enriched_item.item.clone()),
+                    move |result| Message::RestoreComplete(enriched_item, result),
                 )
                 .map(cosmic::Action::App);
             }
 
-            Message::RestoreComplete(result) => {
+            Message::RestoreComplete(enriched_item, result) => {
                 match result {
                     Ok(path) => {
                         eprintln!("✅ Restored to: {}", path.display());
+                        self.last_restored = Some(path.clone());
+                        self.unpin(&enriched_item.item);
                         // Watcher will auto-reload list via TrashStatusChanged
+
+                        if self.config.notify_restore {
+                            return notify(NotificationEvent::Restored {
+                                path,
+                                name: enriched_item.item.name.to_string_lossy().into_owned(),
+                            });
+                        }
                     }
                     Err(e) => {
-                        eprintln!("❌ Restore failed: {e}");
-                        self.operation_error = Some(format!("Failed to restore: {e}"));
+                        let event = NotificationEvent::RestoreFailed {
+                            id: enriched_item.item.id.to_string_lossy().into_owned(),
+                            name: enriched_item.item.name.to_string_lossy().into_owned(),
+                            error: e.message(),
+                        };
+                        let task =
+                            self.handle_trash_error(Operation::Restore, vec![enriched_item], e);
+
+                        if self.config.notify_restore_failure {
+                            return Task::batch([task, notify(event)]);
+                        }
+                        return task;
                     }
                 }
             }
 
             Message::DeleteItem(enriched_item) => {
+                if self.config.secure_erase {
+                    return self.update(Message::SecureDeleteItem(enriched_item));
+                }
+
                 return Task::perform(
-                    trash_operations::delete_item(enriched_item.item),
-                    |result| Message::DeleteComplete(result.map_err(|e| e.to_string())),
+                    trash_operations::delete_item(enriched_item.item.clone()),
+                    move |result| Message::DeleteComplete(enriched_item, result),
                 )
                 .map(cosmic::Action::App);
             }
 
-            Message::DeleteComplete(result) => {
+            Message::RestoreItems(items) => {
+                self.marked_items.clear();
+                return Task::batch(
+                    items
+                        .into_iter()
+                        .map(|enriched| self.update(Message::RestoreItem(enriched)))
+                        .collect::<Vec<_>>(),
+                );
+            }
+
+            Message::DeleteItems(items) => {
+                self.marked_items.clear();
+                return self.delete_items(items);
+            }
+
+            Message::DeleteItemsComplete(items, result) => {
+                self.secure_erase_progress = None;
+                match result {
+                    Ok(()) => {
+                        eprintln!("✅ {} items permanently deleted", items.len());
+                        for enriched in &items {
+                            self.unpin(&enriched.item);
+                        }
+                    }
+                    Err(e) => {
+                        return self.handle_trash_error(Operation::Delete, items, e);
+                    }
+                }
+            }
+
+            Message::ClearMarked => {
+                self.marked_items.clear();
+            }
+
+            Message::RunCleanup => {
+                self.auto_purge_failed.clear();
+                return load_items();
+            }
+
+            Message::AutoPurgeComplete(purged, result) => {
+                self.auto_purge_in_progress = false;
+                self.secure_erase_progress = None;
                 match result {
-                    Ok(_) => {
+                    Ok(()) => {
+                        eprintln!("✅ Cleanup purged {} items", purged.len());
+                        if self.config.notify_auto_purge {
+                            return notify(NotificationEvent::AutoPurged {
+                                count: purged.len(),
+                            });
+                        }
+                    }
+                    Err(e) => {
+                        // The reload after this error must not retry them right away
+                        let failed: Vec<_> = match &e {
+                            TrashError::PartialFailure { failed } => failed
+                                .iter()
+                                .map(|failure| failure.item.id.clone())
+                                .collect(),
+                            _ => purged
+                                .iter()
+                                .map(|enriched| enriched.item.id.clone())
+                                .collect(),
+                        };
+                        self.auto_purge_failed.extend(failed);
+                        return self.handle_trash_error(Operation::Cleanup, purged, e);
+                    }
+                }
+            }
+
+            Message::RuleDraftPattern(pattern) => {
+                self.rule_draft.pattern = pattern;
+                self.rule_draft_error = None;
+            }
+
+            Message::RuleDraftTarget(index) => {
+                if let Some(target) = RuleTarget::ALL.get(index) {
+                    self.rule_draft.target = *target;
+                }
+            }
+
+            Message::RuleDraftSyntax(index) => {
+                if let Some(syntax) = PatternSyntax::ALL.get(index) {
+                    self.rule_draft.syntax = *syntax;
+                    self.rule_draft_error = None;
+                }
+            }
+
+            Message::RuleDraftAction(index) => {
+                if let Some(action) = RuleAction::ALL.get(index) {
+                    self.rule_draft.action = *action;
+                }
+            }
+
+            Message::AddRule => {
+                if let Err(e) = self.rule_draft.compile() {
+                    self.rule_draft_error = Some(e);
+                    return Task::none();
+                }
+
+                // "Purge immediately" rules apply to what is already in the trash
+                let rule = self.rule_draft.clone();
+                let mut config = self.config.clone();
+                config.cleanup_rules.push(rule.clone());
+                let matched = self.purged_by_config(&config);
+                if matched > 0 {
+                    return self.update(Message::Confirm(Confirmation::AddRule { rule, matched }));
+                }
+                return self.update(Message::SaveRule(rule));
+            }
+
+            Message::SaveRule(rule) => {
+                self.rule_draft = CleanupRule::default();
+                let mut rules = self.config.cleanup_rules.clone();
+                rules.push(rule);
+                self.set_cleanup_rules(rules);
+
+                return self.update(Message::RunCleanup);
+            }
+
+            Message::RemoveRule(index) => {
+                if index >= self.config.cleanup_rules.len() {
+                    return Task::none();
+                }
+
+                // Without a "keep" rule, items it protected may be due
+                let mut config = self.config.clone();
+                config.cleanup_rules.remove(index);
+                let matched = self.purged_by_config(&config);
+                if matched > 0 {
+                    return self.update(Message::Confirm(Confirmation::RemoveRule {
+                        index,
+                        matched,
+                    }));
+                }
+                return self.update(Message::SaveRuleRemoval(index));
+            }
+
+            Message::SaveRuleRemoval(index) => {
+                if index < self.config.cleanup_rules.len() {
+                    let mut rules = self.config.cleanup_rules.clone();
+                    rules.remove(index);
+                    self.set_cleanup_rules(rules);
+                    return self.update(Message::RunCleanup);
+                }
+            }
+
+            Message::TogglePin(enriched_item) => {
+                let id = enriched_item.item.id.to_string_lossy().into_owned();
+                let mut pinned = self.config.pinned_items.clone();
+                if let Some(position) = pinned.iter().position(|pinned| *pinned == id) {
+                    pinned.remove(position);
+                } else {
+                    pinned.push(id);
+                }
+                self.set_pinned_items(pinned);
+            }
+
+            Message::SecureDeleteItem(enriched_item) => {
+                return secure_erase(
+                    SecureEraseTarget::Items(vec![enriched_item.item.clone()]),
+                    self.config.secure_erase_passes,
+                    move |result| Message::DeleteComplete(enriched_item, result),
+                );
+            }
+
+            Message::SecureEraseProgress(progress) => {
+                self.secure_erase_progress = Some(progress);
+            }
+
+            Message::DeleteComplete(enriched_item, result) => {
+                self.secure_erase_progress = None;
+                match result {
+                    Ok(()) => {
                         eprintln!("✅ Item permanently deleted");
+                        self.unpin(&enriched_item.item);
                         // Watcher will auto-reload list via TrashStatusChanged
                     }
                     Err(e) => {
-                        eprintln!("❌ Delete failed: {e}");
-                        self.operation_error = Some(format!("Failed to delete: {e}"));
+                        return self.handle_trash_error(Operation::Delete, vec![enriched_item], e);
+                    }
+                }
+            }
+
+            Message::RetryOperation(index) => {
+                if index >= self.operation_errors.len() {
+                    return Task::none();
+                }
+                let error = self.operation_errors.remove(index);
+
+                // Every affected item, not just the first
+                let retry: fn(EnrichedTrashItem) -> Message = match error.operation {
+                    Operation::Empty => return self.update(Message::EmptyTrash),
+                    Operation::Delete => return self.delete_items(error.items),
+                    Operation::Restore => Message::RestoreItem,
+                    Operation::Open => Message::OpenItem,
+                    Operation::FileManager | Operation::Export | Operation::Cleanup => {
+                        return Task::none();
+                    }
+                };
+                return Task::batch(
+                    error
+                        .items
+                        .into_iter()
+                        .map(|enriched| self.update(retry(enriched)))
+                        .collect::<Vec<_>>(),
+                );
+            }
+
+            Message::RetryFailedItem(index, item_index) => {
+                let Some(error) = self.operation_errors.get_mut(index) else {
+                    return Task::none();
+                };
+                // Same operation as the one that failed (an empty deletes items)
+                let retry: fn(EnrichedTrashItem) -> Message = match error.operation {
+                    Operation::Empty | Operation::Delete => Message::DeleteItem,
+                    Operation::Restore => Message::RestoreItem,
+                    Operation::Open => Message::OpenItem,
+                    Operation::FileManager | Operation::Export | Operation::Cleanup => {
+                        return Task::none();
                     }
+                };
+                let Some(enriched) = error.take_item(item_index) else {
+                    return Task::none();
+                };
+                if error.items.is_empty() {
+                    self.operation_errors.remove(index);
                 }
+                return self.update(retry(enriched));
+            }
+
+            Message::DismissError(index) => {
+                if index < self.operation_errors.len() {
+                    self.operation_errors.remove(index);
+                }
+            }
+
+            Message::DismissAllErrors => {
+                self.operation_errors.clear();
             }
 
             Message::TogglePopup => {
-                return if let Some(p) = self.popup.take() {
-                    destroy_popup(p)
-                } else {
-                    let new_id = Id::unique();
-                    self.popup.replace(new_id);
-                    let mut popup_settings = self.core.applet.get_popup_settings(
-                        self.core.main_window_id().unwrap(),
-                        new_id,
-                        None,
-                        None,
-                        None,
-                    );
-                    popup_settings.positioner.size_limits = Limits::NONE
-                        .max_width(372.0)
-                        .min_width(300.0)
-                        .min_height(200.0)
-                        .max_height(1080.0);
-                    get_popup(popup_settings)
+                return self.toggle_popup(PopupPage::Main);
+            }
+
+            Message::PanelAction(action) => match action {
+                PanelAction::Nothing => {}
+                PanelAction::TogglePopup => return self.toggle_popup(PopupPage::Main),
+                PanelAction::ContextMenu => return self.toggle_popup(PopupPage::ContextMenu),
+                PanelAction::OpenTrash => return self.update(Message::OpenTrashFolder),
+            },
+
+            Message::CycleRecent(step) => {
+                let count = self.trash_items.len().min(TOOLTIP_RECENT);
+                self.recent_index = match (self.recent_index, count) {
+                    (_, 0) => None,
+                    // First scroll shows the newest item, then wraps around
+                    (None, _) => Some(0),
+                    (Some(index), count) if step > 0 => Some((index + 1) % count),
+                    (Some(index), count) => Some((index + count - 1) % count),
                 };
             }
 
+            Message::PanelExit => {
+                self.recent_index = None;
+            }
+
             Message::PopupClosed(id) => {
                 if self.popup.as_ref() == Some(&id) {
                     self.popup = None;
+                    self.popup_page = PopupPage::Main;
+                    self.pending_confirmation = None;
+                    self.reset_navigation();
+                    return self.save_custom_file_manager();
+                }
+            }
+
+            Message::ShowPage(page) => {
+                let leaving_settings =
+                    self.popup_page == PopupPage::Settings && page != PopupPage::Settings;
+                self.popup_page = page;
+                if leaving_settings {
+                    return self.save_custom_file_manager();
                 }
             }
 
@@ -368,3 +1374,458 @@ impl cosmic::Application for AppModel {
         Some(cosmic::applet::style())
     }
 }
+
+impl AppModel {
+    /// Opens the popup on `page`, or closes it if open
+    fn toggle_popup(&mut self, page: PopupPage) -> Task<cosmic::Action<Message>> {
+        if let Some(p) = self.popup.take() {
+            return Task::batch([self.save_custom_file_manager(), destroy_popup(p)]);
+        }
+
+        let new_id = Id::unique();
+        self.popup.replace(new_id);
+        self.popup_page = page;
+        self.pending_confirmation = None;
+        self.reset_navigation();
+        let mut popup_settings = self.core.applet.get_popup_settings(
+            self.core.main_window_id().unwrap(),
+            new_id,
+            None,
+            None,
+            None,
+        );
+        // The context menu only holds three entries
+        let (min_width, min_height) = if page == PopupPage::ContextMenu {
+            (200.0, 0.0)
+        } else {
+            (300.0, 200.0)
+        };
+        popup_settings.positioner.size_limits = Limits::NONE
+            .max_width(372.0)
+            .min_width(min_width)
+            .min_height(min_height)
+            .max_height(1080.0);
+        get_popup(popup_settings)
+    }
+
+    /// Items shown in the list (matching the search query), in list order
+    fn visible_items(&self) -> impl Iterator<Item = &EnrichedTrashItem> {
+        self.trash_items
+            .iter()
+            .filter(|enriched| enriched.matches(&self.search_query))
+    }
+
+    /// Marked items, or the focused one when nothing is marked
+    fn keyboard_targets(&self) -> Vec<EnrichedTrashItem> {
+        self.visible_items()
+            .filter(|enriched| {
+                if self.marked_items.is_empty() {
+                    self.focused_item.as_ref() == Some(&enriched.item.id)
+                } else {
+                    self.marked_items.contains(&enriched.item.id)
+                }
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// Handles a popup keyboard shortcut
+    fn keyboard(&mut self, action: KeyboardAction) -> Task<cosmic::Action<Message>> {
+        if action == KeyboardAction::Close {
+            if self.pending_confirmation.take().is_some() {
+                return Task::none();
+            }
+            match self.popup_page {
+                PopupPage::Main | PopupPage::ContextMenu => {}
+                PopupPage::Settings => {
+                    return self.update(Message::ShowPage(PopupPage::Main));
+                }
+                PopupPage::CleanupRules => {
+                    self.popup_page = PopupPage::Settings;
+                    return Task::none();
+                }
+            }
+            self.reset_navigation();
+            return self.popup.take().map_or_else(Task::none, destroy_popup);
+        }
+
+        // The remaining shortcuts act on the items list
+        if self.popup_page != PopupPage::Main {
+            return Task::none();
+        }
+
+        match action {
+            KeyboardAction::FocusNext | KeyboardAction::FocusPrevious => {
+                let visible: Vec<_> = self
+                    .visible_items()
+                    .map(|enriched| enriched.item.id.clone())
+                    .collect();
+                if visible.is_empty() {
+                    return Task::none();
+                }
+
+                let current = self
+                    .focused_item
+                    .as_ref()
+                    .and_then(|id| visible.iter().position(|visible| visible == id));
+                let index = match (action, current) {
+                    (KeyboardAction::FocusNext, Some(index)) => (index + 1).min(visible.len() - 1),
+                    (KeyboardAction::FocusPrevious, Some(index)) => index.saturating_sub(1),
+                    (KeyboardAction::FocusPrevious, None) => visible.len() - 1,
+                    _ => 0,
+                };
+                self.focused_item = Some(visible[index].clone());
+
+                return ui_items::scroll_to(index, visible.len());
+            }
+            KeyboardAction::Restore => {
+                let targets = self.keyboard_targets();
+                if !targets.is_empty() {
+                    return self.update(Message::RestoreItems(targets));
+                }
+            }
+            KeyboardAction::Purge => {
+                let mut targets = self.keyboard_targets();
+                let confirmation = match targets.len() {
+                    0 => return Task::none(),
+                    1 => Confirmation::Delete {
+                        item: targets.remove(0),
+                        secure: false,
+                    },
+                    _ => Confirmation::DeleteMarked { items: targets },
+                };
+                return self.update(Message::Confirm(confirmation));
+            }
+            KeyboardAction::SelectAll => {
+                let visible: Vec<_> = self
+                    .visible_items()
+                    .map(|enriched| enriched.item.id.clone())
+                    .collect();
+                if visible.iter().all(|id| self.marked_items.contains(id)) {
+                    self.marked_items.clear();
+                } else {
+                    self.marked_items = visible;
+                }
+            }
+            KeyboardAction::FocusSearch => {
+                return widget::text_input::focus(ui_popup::SEARCH_INPUT.clone());
+            }
+            KeyboardAction::Close => {}
+        }
+
+        Task::none()
+    }
+
+    /// Forgets focus, marks and search (popup closed or reopened)
+    fn reset_navigation(&mut self) {
+        self.focused_item = None;
+        self.marked_items.clear();
+        self.search_query.clear();
+    }
+
+    /// Records a failed operation for the error banner
+    fn push_error(
+        &mut self,
+        operation: Operation,
+        items: Vec<EnrichedTrashItem>,
+        error: impl Into<TrashError>,
+    ) {
+        operation_error::push_recent(
+            &mut self.operation_errors,
+            OperationError::new(operation, items, error.into()),
+        );
+    }
+
+    /// Switches to `config`, reacting to changes that affect the current state
+    fn apply_config(&mut self, config: Config) -> Task<cosmic::Action<Message>> {
+        let previous = std::mem::replace(&mut self.config, config);
+
+        if previous.default_sort != self.config.default_sort {
+            self.sort_ascending = self.config.default_sort.is_ascending();
+            EnrichedTrashItem::sort_items(&mut self.trash_items, self.sort_ascending);
+        }
+
+        if previous.retention_days != self.config.retention_days
+            || previous.cleanup_rules != self.config.cleanup_rules
+        {
+            return self.update(Message::RunCleanup);
+        }
+
+        Task::none()
+    }
+
+    /// Updates and persists the pinned items
+    fn set_pinned_items(&mut self, pinned: Vec<String>) {
+        match &self.config_handler {
+            Some(handler) => {
+                if let Err(e) = self.config.set_pinned_items(handler, pinned) {
+                    eprintln!("Failed to save pinned items: {e}");
+                }
+            }
+            None => self.config.pinned_items = pinned, // Not persisted
+        }
+    }
+
+    /// Updates and persists the cleanup rules
+    fn set_cleanup_rules(&mut self, rules: Vec<CleanupRule>) {
+        match &self.config_handler {
+            Some(handler) => {
+                if let Err(e) = self.config.set_cleanup_rules(handler, rules) {
+                    eprintln!("Failed to save cleanup rules: {e}");
+                }
+            }
+            None => self.config.cleanup_rules = rules, // Not persisted
+        }
+    }
+
+    /// Persists the custom file manager command being edited, if any
+    fn save_custom_file_manager(&mut self) -> Task<cosmic::Action<Message>> {
+        match self.custom_file_manager_draft.take() {
+            Some(template) if template != self.config.custom_file_manager => self.update(
+                Message::ChangeSetting(SettingChange::CustomFileManager(template)),
+            ),
+            _ => Task::none(),
+        }
+    }
+
+    /// Number of items cleanup would purge right away under `config` that the
+    /// current config keeps
+    fn purged_by_config(&self, config: &Config) -> usize {
+        if config.retention_days == self.config.retention_days
+            && config.cleanup_rules == self.config.cleanup_rules
+        {
+            return 0;
+        }
+
+        let current = CleanupPolicy::from_config(&self.config);
+        let updated = CleanupPolicy::from_config(config);
+        let now = chrono::Utc::now().timestamp();
+
+        self.trash_items
+            .iter()
+            .filter(|enriched| {
+                updated.should_purge(&enriched.item, now)
+                    && !current.should_purge(&enriched.item, now)
+            })
+            .count()
+    }
+
+    /// Permanently deletes items as one operation (securely erased if enabled)
+    fn delete_items(&self, items: Vec<EnrichedTrashItem>) -> Task<cosmic::Action<Message>> {
+        let trash_items = items.iter().map(|enriched| enriched.item.clone()).collect();
+
+        if self.config.secure_erase {
+            return secure_erase(
+                SecureEraseTarget::Items(trash_items),
+                self.config.secure_erase_passes,
+                move |result| Message::DeleteItemsComplete(items, result),
+            );
+        }
+
+        Task::perform(trash_operations::delete_items(trash_items), move |result| {
+            Message::DeleteItemsComplete(items, result)
+        })
+        .map(cosmic::Action::App)
+    }
+
+    /// Starts purging items that cleanup policies say are due
+    ///
+    /// Deleting triggers the watcher, whose reload finds nothing left to do.
+    /// Items that failed to purge are skipped until the next `RunCleanup`, so
+    /// the reload after a failure does not loop.
+    fn auto_purge(&mut self, items: &[EnrichedTrashItem]) -> Task<cosmic::Action<Message>> {
+        if self.auto_purge_in_progress {
+            return Task::none();
+        }
+
+        let policy = CleanupPolicy::from_config(&self.config);
+        if !policy.is_active() {
+            return Task::none();
+        }
+
+        let now = chrono::Utc::now().timestamp();
+        let purged: Vec<EnrichedTrashItem> = items
+            .iter()
+            .filter(|enriched| policy.should_purge(&enriched.item, now))
+            .filter(|enriched| !self.auto_purge_failed.contains(&enriched.item.id))
+            .cloned()
+            .collect();
+        if purged.is_empty() {
+            return Task::none();
+        }
+
+        self.auto_purge_in_progress = true;
+        let due = purged
+            .iter()
+            .map(|enriched| enriched.item.clone())
+            .collect();
+
+        if self.config.secure_erase {
+            return secure_erase(
+                SecureEraseTarget::Items(due),
+                self.config.secure_erase_passes,
+                move |result| Message::AutoPurgeComplete(purged, result),
+            );
+        }
+
+        Task::perform(trash_operations::delete_items(due), move |result| {
+            Message::AutoPurgeComplete(purged, result)
+        })
+        .map(cosmic::Action::App)
+    }
+
+    /// Drops the pin of an item that left the trash (its `.trashinfo` path may be reused)
+    fn unpin(&mut self, item: &trash::TrashItem) {
+        if self.config.is_pinned(item) {
+            let id = item.id.to_string_lossy();
+            let pinned = self
+                .config
+                .pinned_items
+                .iter()
+                .filter(|pinned| pinned.as_str() != id)
+                .cloned()
+                .collect();
+            self.set_pinned_items(pinned);
+        }
+    }
+
+    /// Records a failed trash operation and reacts to its kind
+    ///
+    /// Missing items and partial failures mean the list is stale, so it is
+    /// reloaded right away instead of waiting for the watcher. Conflicts get a
+    /// "Show conflicting file" action in the banner (see `ui_error_banner`).
+    fn handle_trash_error(
+        &mut self,
+        operation: Operation,
+        items: Vec<EnrichedTrashItem>,
+        error: TrashError,
+    ) -> Task<cosmic::Action<Message>> {
+        let reload = matches!(
+            error,
+            TrashError::NotFound { .. } | TrashError::PartialFailure { .. }
+        );
+
+        self.push_error(operation, items, error);
+
+        if reload { load_items() } else { Task::none() }
+    }
+}
+
+/// What a secure erase covers
+enum SecureEraseTarget {
+    /// Specific items
+    Items(Vec<trash::TrashItem>),
+    /// The whole trash, except these pinned `.trashinfo` paths
+    Trash(Vec<String>),
+}
+
+/// Runs a secure erase, streaming progress before the completion message
+fn secure_erase(
+    target: SecureEraseTarget,
+    passes: u32,
+    complete: impl FnOnce(Result<(), TrashError>) -> Message + Send + 'static,
+) -> Task<cosmic::Action<Message>> {
+    use cosmic::iced_futures::futures::channel::mpsc;
+
+    Task::run(
+        stream::channel(16, move |mut output: mpsc::Sender<Message>| async move {
+            let progress_output = output.clone();
+            let on_progress = move |progress| {
+                // Dropped when the channel is full; the next update catches up
+                let _ = progress_output
+                    .clone()
+                    .try_send(Message::SecureEraseProgress(progress));
+            };
+
+            let result = match target {
+                SecureEraseTarget::Items(items) => {
+                    trash_operations::secure_delete_items(items, passes, on_progress).await
+                }
+                SecureEraseTarget::Trash(pinned) => {
+                    trash_operations::secure_empty_trash(pinned, passes, on_progress).await
+                }
+            };
+
+            if let Err(e) = output.send(complete(result)).await {
+                eprintln!("Failed to send secure erase result: {e:?}");
+            }
+        }),
+        cosmic::Action::App,
+    )
+}
+
+/// Maps a key press in the popup to its shortcut
+///
+/// Only sees keys no widget captured, so typing in the search field does not
+/// trigger shortcuts.
+fn keyboard_shortcut(key: Key, modifiers: Modifiers) -> Option<Message> {
+    let action = match key.as_ref() {
+        Key::Named(Named::ArrowDown) => KeyboardAction::FocusNext,
+        Key::Named(Named::ArrowUp) => KeyboardAction::FocusPrevious,
+        Key::Named(Named::Enter) => KeyboardAction::Restore,
+        Key::Named(Named::Delete) if modifiers.shift() => KeyboardAction::Purge,
+        Key::Named(Named::Escape) => KeyboardAction::Close,
+        Key::Character("a") if modifiers.command() => KeyboardAction::SelectAll,
+        Key::Character("/") => KeyboardAction::FocusSearch,
+        _ => return None,
+    };
+    Some(Message::Keyboard(action))
+}
+
+/// Lists the trash and enriches the items (sizes walk folders) off the UI thread
+fn load_items() -> Task<cosmic::Action<Message>> {
+    Task::perform(
+        async {
+            let items = trash_operations::list_items().await.unwrap_or_default();
+            tokio::task::spawn_blocking(move || {
+                items
+                    .into_iter()
+                    .map(EnrichedTrashItem::from_trash_item)
+                    .collect()
+            })
+            .await
+            .unwrap_or_default()
+        },
+        |items| cosmic::Action::App(Message::TrashItemsLoaded(items)),
+    )
+}
+
+/// Sends a desktop notification in the background (failures are only logged)
+///
+/// Its id comes back as `NotificationSent`, so only its actions are answered.
+fn notify(event: NotificationEvent) -> Task<cosmic::Action<Message>> {
+    Task::perform(
+        async move { notifications::send(event).await.map_err(|e| e.to_string()) },
+        Message::NotificationSent,
+    )
+    .map(cosmic::Action::App)
+}
+
+/// Asks for a destination (save dialog) and writes the export there
+///
+/// Format follows the chosen extension (`.csv` → CSV, otherwise JSON).
+/// Returns `Ok(None)` when the dialog is cancelled.
+async fn export_list(
+    items: Vec<EnrichedTrashItem>,
+    units: SizeUnits,
+) -> Result<Option<std::path::PathBuf>, String> {
+    use cosmic::dialog::file_chooser;
+
+    let dialog = file_chooser::save::Dialog::new()
+        .title(fl!("export-dialog-title"))
+        .file_name(format!("trash-contents.{}", ExportFormat::Json.extension()));
+
+    let response = match dialog.save_file().await {
+        Ok(response) => response,
+        Err(file_chooser::Error::Cancelled) => return Ok(None),
+        Err(e) => return Err(e.to_string()),
+    };
+
+    let Some(path) = response.url().and_then(|url| url.to_file_path().ok()) else {
+        return Ok(None);
+    };
+
+    trash_export::export_to_file(items, ExportFormat::from_path(&path), units, path)
+        .await
+        .map(Some)
+}
@@ -0,0 +1,430 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Backend: Automatic cleanup policies
+//!
+//! Glob or regex rules from `Config::cleanup_rules`, matched against an item's
+//! name or original location, decide what automatic cleanup may do:
+//! `Keep` protects matches from retention/prune, `PurgeImmediately` deletes
+//! matches as soon as they reach the trash. Pinned items always win.
+
+use crate::config::{self, Config};
+use crate::fl;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// Pattern language of a rule
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PatternSyntax {
+    /// `*` (within a path component), `**` (across components), `?`
+    #[default]
+    Glob,
+    /// Regular expression (unanchored)
+    Regex,
+}
+
+/// What a rule is matched against
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RuleTarget {
+    /// File or folder name (`TrashItem::name`)
+    #[default]
+    Name,
+    /// Folder it was trashed from (`TrashItem::original_parent`); a plain path
+    /// also matches everything beneath it
+    Location,
+}
+
+/// What automatic cleanup does with matching items
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RuleAction {
+    /// Never auto-purged (retention and prune skip it)
+    #[default]
+    Keep,
+    /// Purged as soon as it is in the trash
+    PurgeImmediately,
+}
+
+impl PatternSyntax {
+    pub const ALL: [Self; 2] = [Self::Glob, Self::Regex];
+
+    #[must_use]
+    pub fn name(self) -> String {
+        match self {
+            Self::Glob => fl!("syntax-glob"),
+            Self::Regex => fl!("syntax-regex"),
+        }
+    }
+}
+
+impl RuleTarget {
+    pub const ALL: [Self; 2] = [Self::Name, Self::Location];
+
+    #[must_use]
+    pub fn name(self) -> String {
+        match self {
+            Self::Name => fl!("target-name"),
+            Self::Location => fl!("target-location"),
+        }
+    }
+}
+
+impl RuleAction {
+    pub const ALL: [Self; 2] = [Self::Keep, Self::PurgeImmediately];
+
+    #[must_use]
+    pub fn name(self) -> String {
+        match self {
+            Self::Keep => fl!("action-keep"),
+            Self::PurgeImmediately => fl!("action-purge-immediately"),
+        }
+    }
+}
+
+/// A cleanup rule as stored in `Config`
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CleanupRule {
+    pub pattern: String,
+    pub syntax: PatternSyntax,
+    pub target: RuleTarget,
+    pub action: RuleAction,
+}
+
+impl CleanupRule {
+    /// Compiles the rule, or explains why the pattern is invalid
+    ///
+    /// # Errors
+    ///
+    /// Returns error for empty patterns and invalid regular expressions
+    pub fn compile(&self) -> Result<CompiledRule, String> {
+        let pattern = self.pattern.trim();
+        if pattern.is_empty() {
+            return Err(fl!("rule-pattern-empty"));
+        }
+
+        let pattern = match self.target {
+            RuleTarget::Location => expand_home(pattern),
+            RuleTarget::Name => pattern.to_string(),
+        };
+
+        let source = match (self.syntax, self.target) {
+            (PatternSyntax::Regex, _) => pattern,
+            (PatternSyntax::Glob, RuleTarget::Name) => format!("^{}$", glob_to_regex(&pattern)),
+            (PatternSyntax::Glob, RuleTarget::Location) => {
+                let pattern = pattern.trim_end_matches('/');
+                // A plain folder covers its subfolders too
+                format!("^{}(/.*)?$", glob_to_regex(pattern))
+            }
+        };
+
+        Regex::new(&source)
+            .map(|regex| CompiledRule {
+                regex,
+                target: self.target,
+                action: self.action,
+            })
+            .map_err(|e| e.to_string())
+    }
+}
+
+/// A rule ready for matching
+#[derive(Debug, Clone)]
+pub struct CompiledRule {
+    regex: Regex,
+    target: RuleTarget,
+    action: RuleAction,
+}
+
+impl CompiledRule {
+    /// Whether the rule matches `item`
+    #[must_use]
+    pub fn matches(&self, item: &trash::TrashItem) -> bool {
+        match self.target {
+            RuleTarget::Name => self.regex.is_match(&item.name.to_string_lossy()),
+            RuleTarget::Location => self.regex.is_match(&item.original_parent.to_string_lossy()),
+        }
+    }
+}
+
+/// Pinned items, compiled rules and retention, evaluated together
+#[derive(Debug, Clone, Default)]
+pub struct CleanupPolicy {
+    pinned: Vec<String>,
+    rules: Vec<CompiledRule>,
+    /// Days before items are auto-purged (0 disables retention)
+    retention_days: u32,
+}
+
+impl CleanupPolicy {
+    /// Builds the policy from `config`, skipping (and logging) invalid rules
+    #[must_use]
+    pub fn from_config(config: &Config) -> Self {
+        let rules = config
+            .cleanup_rules
+            .iter()
+            .filter_map(|rule| match rule.compile() {
+                Ok(compiled) => Some(compiled),
+                Err(e) => {
+                    eprintln!("Ignoring cleanup rule {:?}: {e}", rule.pattern);
+                    None
+                }
+            })
+            .collect();
+
+        Self {
+            pinned: config.pinned_items.clone(),
+            rules,
+            retention_days: config.retention_days,
+        }
+    }
+
+    /// Pinned or matched by a `Keep` rule: never purged automatically
+    #[must_use]
+    pub fn is_protected(&self, item: &trash::TrashItem) -> bool {
+        config::is_pinned(&self.pinned, item) || self.has_match(item, RuleAction::Keep)
+    }
+
+    /// Whether automatic cleanup should purge `item` at `now` (Unix seconds)
+    #[must_use]
+    pub fn should_purge(&self, item: &trash::TrashItem, now: i64) -> bool {
+        if self.is_protected(item) {
+            return false;
+        }
+        if self.has_match(item, RuleAction::PurgeImmediately) {
+            return true;
+        }
+        self.retention_days > 0 && item.time_deleted < now - i64::from(self.retention_days) * DAY
+    }
+
+    /// When retention next expires one of `items` (Unix seconds)
+    ///
+    /// None without retention or when every item is protected.
+    #[must_use]
+    pub fn next_due<'a>(
+        &self,
+        items: impl IntoIterator<Item = &'a trash::TrashItem>,
+    ) -> Option<i64> {
+        if self.retention_days == 0 {
+            return None;
+        }
+        items
+            .into_iter()
+            .filter(|item| !self.is_protected(item))
+            .map(|item| item.time_deleted + i64::from(self.retention_days) * DAY)
+            .min()
+    }
+
+    /// Whether any automatic cleanup is configured
+    #[must_use]
+    pub fn is_active(&self) -> bool {
+        self.retention_days > 0
+            || self
+                .rules
+                .iter()
+                .any(|rule| rule.action == RuleAction::PurgeImmediately)
+    }
+
+    fn has_match(&self, item: &trash::TrashItem, action: RuleAction) -> bool {
+        self.rules
+            .iter()
+            .any(|rule| rule.action == action && rule.matches(item))
+    }
+}
+
+const DAY: i64 = 24 * 60 * 60;
+
+/// Translates a glob into (unanchored) regex source
+fn glob_to_regex(glob: &str) -> String {
+    let mut source = String::with_capacity(glob.len() * 2);
+    let mut chars = glob.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '*' if chars.peek() == Some(&'*') => {
+                chars.next();
+                source.push_str(".*");
+            }
+            '*' => source.push_str("[^/]*"),
+            '?' => source.push_str("[^/]"),
+            c => source.push_str(&regex::escape(&c.to_string())),
+        }
+    }
+
+    source
+}
+
+/// Expands a leading `~` to the home directory
+fn expand_home(pattern: &str) -> String {
+    let Some(rest) = pattern.strip_prefix('~') else {
+        return pattern.to_string();
+    };
+    if !(rest.is_empty() || rest.starts_with('/')) {
+        return pattern.to_string(); // `~user` is not supported
+    }
+
+    match std::env::var_os("HOME") {
+        Some(home) => format!("{}{rest}", Path::new(&home).display()),
+        None => pattern.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    const NOW: i64 = 1_700_000_000;
+
+    fn item(name: &str, parent: &str, time_deleted: i64) -> trash::TrashItem {
+        trash::TrashItem {
+            id: format!("/home/u/.local/share/Trash/info/{name}.trashinfo").into(),
+            name: name.into(),
+            original_parent: PathBuf::from(parent),
+            time_deleted,
+        }
+    }
+
+    fn rule(pattern: &str, syntax: PatternSyntax, target: RuleTarget) -> CleanupRule {
+        CleanupRule {
+            pattern: pattern.to_string(),
+            syntax,
+            target,
+            action: RuleAction::Keep,
+        }
+    }
+
+    fn matches(rule: &CleanupRule, item: &trash::TrashItem) -> bool {
+        rule.compile().unwrap().matches(item)
+    }
+
+    fn cleanup_policy(
+        rules: &[(&str, RuleAction)],
+        pinned: &[&trash::TrashItem],
+        days: u32,
+    ) -> CleanupPolicy {
+        CleanupPolicy::from_config(&Config {
+            cleanup_rules: rules
+                .iter()
+                .map(|(pattern, action)| CleanupRule {
+                    action: *action,
+                    ..rule(pattern, PatternSyntax::Glob, RuleTarget::Name)
+                })
+                .collect(),
+            pinned_items: pinned
+                .iter()
+                .map(|item| item.id.to_string_lossy().into_owned())
+                .collect(),
+            retention_days: days,
+            ..Config::default()
+        })
+    }
+
+    #[test]
+    fn glob_to_regex_translates_wildcards() {
+        assert_eq!(glob_to_regex("*.tmp"), r"[^/]*\.tmp");
+        assert_eq!(glob_to_regex("file?.log"), r"file[^/]\.log");
+        assert_eq!(glob_to_regex("/home/**/cache"), "/home/.*/cache");
+        assert_eq!(glob_to_regex("a+b (1)"), r"a\+b \(1\)");
+    }
+
+    #[test]
+    fn name_globs_match_the_whole_name() {
+        let tmp = rule("*.tmp", PatternSyntax::Glob, RuleTarget::Name);
+        assert!(matches(&tmp, &item("a.tmp", "/home/u", NOW)));
+        assert!(!matches(&tmp, &item("a.tmp.bak", "/home/u", NOW)));
+        assert!(!matches(&tmp, &item("tmp", "/home/u", NOW)));
+
+        // Regexes are unanchored
+        let regex = rule(r"\.tmp", PatternSyntax::Regex, RuleTarget::Name);
+        assert!(matches(&regex, &item("a.tmp.bak", "/home/u", NOW)));
+    }
+
+    #[test]
+    fn location_globs_cover_subfolders() {
+        let downloads = rule(
+            "/home/u/Downloads/",
+            PatternSyntax::Glob,
+            RuleTarget::Location,
+        );
+        assert!(matches(&downloads, &item("a", "/home/u/Downloads", NOW)));
+        assert!(matches(
+            &downloads,
+            &item("a", "/home/u/Downloads/iso/old", NOW)
+        ));
+        assert!(!matches(&downloads, &item("a", "/home/u/Downloads2", NOW)));
+        assert!(!matches(&downloads, &item("a", "/home/u", NOW)));
+
+        // `*` stays within one component, `**` crosses them
+        let single = rule("/home/*/cache", PatternSyntax::Glob, RuleTarget::Location);
+        assert!(matches(&single, &item("a", "/home/u/cache", NOW)));
+        assert!(!matches(&single, &item("a", "/home/u/.var/cache", NOW)));
+        let any = rule("/home/**/cache", PatternSyntax::Glob, RuleTarget::Location);
+        assert!(matches(&any, &item("a", "/home/u/.var/cache", NOW)));
+        assert!(matches(&any, &item("a", "/home/u/.var/cache/thumbs", NOW)));
+    }
+
+    #[test]
+    fn invalid_patterns_do_not_compile() {
+        assert!(
+            rule("  ", PatternSyntax::Glob, RuleTarget::Name)
+                .compile()
+                .is_err()
+        );
+        assert!(
+            rule("(", PatternSyntax::Regex, RuleTarget::Name)
+                .compile()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn keep_and_pins_win_over_purge_immediately() {
+        let log = item("a.log", "/home/u", NOW);
+        let tmp = item("a.tmp", "/home/u", NOW);
+        let pinned = item("b.tmp", "/home/u", NOW);
+        let policy = cleanup_policy(
+            &[
+                ("*.tmp", RuleAction::PurgeImmediately),
+                ("a.*", RuleAction::Keep),
+            ],
+            &[&pinned],
+            0,
+        );
+
+        assert!(!policy.should_purge(&log, NOW)); // Keep, no retention
+        assert!(!policy.should_purge(&tmp, NOW)); // Keep beats PurgeImmediately
+        assert!(!policy.should_purge(&pinned, NOW)); // Pinned beats PurgeImmediately
+        assert!(policy.should_purge(&item("c.tmp", "/home/u", NOW), NOW));
+        assert!(policy.is_active());
+    }
+
+    #[test]
+    fn retention_purges_expired_unprotected_items() {
+        let expired = item("old", "/home/u", NOW - 31 * DAY);
+        let recent = item("new", "/home/u", NOW - DAY);
+        let kept = item("old.keep", "/home/u", NOW - 31 * DAY);
+        let policy = cleanup_policy(&[("*.keep", RuleAction::Keep)], &[], 30);
+
+        assert!(policy.should_purge(&expired, NOW));
+        assert!(!policy.should_purge(&recent, NOW));
+        assert!(!policy.should_purge(&kept, NOW));
+    }
+
+    #[test]
+    fn next_due_skips_protected_items() {
+        let oldest = item("a.keep", "/home/u", NOW - 10 * DAY);
+        let older = item("b", "/home/u", NOW - 5 * DAY);
+        let pinned = item("c", "/home/u", NOW - 8 * DAY);
+        let newest = item("d", "/home/u", NOW - DAY);
+        let items = [oldest, older, pinned.clone(), newest];
+
+        let policy = cleanup_policy(&[("*.keep", RuleAction::Keep)], &[&pinned], 30);
+        assert_eq!(policy.next_due(&items), Some(NOW + 25 * DAY));
+
+        // Without retention nothing is scheduled, even with purge rules
+        let policy = cleanup_policy(&[("*", RuleAction::PurgeImmediately)], &[], 0);
+        assert_eq!(policy.next_due(&items), None);
+
+        let policy = cleanup_policy(&[("*", RuleAction::Keep)], &[], 30);
+        assert_eq!(policy.next_due(&items), None);
+    }
+}
@@ -0,0 +1,468 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Headless CLI mode
+//!
+//! Same backend as the applet (`trash_operations` + `TrashStatus`), usable from
+//! scripts and testable without a compositor:
+//!
+//! ```text
+//! cosmic-applet-trash list --json
+//! cosmic-applet-trash put ~/Downloads/old.iso
+//! cosmic-applet-trash prune --older-than 30d
+//! ```
+//!
+//! Permanent deletion honours the applet config like the popup and the D-Bus
+//! service do (pinned items, `Config::secure_erase`).
+
+use crate::cleanup_rules::CleanupPolicy;
+use crate::config::Config;
+use crate::error::TrashError;
+use crate::trash_export::{self, ExportFormat, ExportRecord};
+use crate::trash_item_metadata::EnrichedTrashItem;
+use crate::trash_operations;
+use crate::trash_status::TrashStatus;
+use clap::{Parser, Subcommand};
+use serde::Serialize;
+use std::ffi::OsString;
+use std::path::PathBuf;
+
+#[derive(Debug, Parser)]
+#[command(
+    name = "cosmic-applet-trash",
+    version,
+    about = "COSMIC trash applet (headless mode)"
+)]
+struct Cli {
+    /// Print machine-readable JSON instead of text
+    #[arg(long, global = true)]
+    json: bool,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// List items in the trash
+    List,
+    /// Move files or folders to the trash
+    Put {
+        #[arg(required = true)]
+        paths: Vec<PathBuf>,
+    },
+    /// Restore items (by id, name or original path) to their original location
+    Restore {
+        #[arg(required = true)]
+        items: Vec<String>,
+    },
+    /// Permanently delete items (by id, name or original path)
+    Purge {
+        #[arg(required = true)]
+        items: Vec<String>,
+    },
+    /// Permanently delete every item in the trash except pinned ones
+    Empty,
+    /// Show whether the trash is empty and how many items it holds
+    Status,
+    /// Permanently delete items trashed longer ago than a duration (pinned items and
+    /// items matched by "never auto-purge" rules are kept)
+    Prune {
+        /// Age such as `30d`, `2w`, `12h` or `45m`
+        #[arg(long, value_parser = parse_age)]
+        older_than: i64,
+        /// Only list what would be deleted
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Export the trash contents as JSON or CSV
+    Export {
+        #[arg(long, value_parser = ["json", "csv"], default_value = "json")]
+        format: String,
+        /// Write to a file instead of stdout
+        #[arg(long, short)]
+        output: Option<PathBuf>,
+    },
+}
+
+/// Runs the CLI if any argument is given (the panel starts the applet without any)
+///
+/// Returns the process exit code, or `None` to start the applet
+#[must_use]
+pub fn run_if_requested() -> Option<i32> {
+    let cli = match parse(std::env::args_os())? {
+        Ok(cli) => cli,
+        // Help and version also end here (exit code 0)
+        Err(e) => {
+            let _ = e.print();
+            return Some(e.exit_code());
+        }
+    };
+
+    let runtime = match tokio::runtime::Runtime::new() {
+        Ok(runtime) => runtime,
+        Err(e) => {
+            eprintln!("Failed to start async runtime: {e}");
+            return Some(1);
+        }
+    };
+
+    Some(match runtime.block_on(run(cli)) {
+        Ok(()) => 0,
+        Err(e) => {
+            eprintln!("cosmic-applet-trash: {e}");
+            1
+        }
+    })
+}
+
+/// Parses the command line, or `None` without arguments (applet mode)
+fn parse(args: impl IntoIterator<Item = OsString>) -> Option<Result<Cli, clap::Error>> {
+    let args: Vec<OsString> = args.into_iter().collect();
+    (args.len() > 1).then(|| Cli::try_parse_from(args))
+}
+
+async fn run(cli: Cli) -> Result<(), String> {
+    let json = cli.json;
+
+    match cli.command {
+        Command::List => {
+            let items = enrich(trash_operations::list_items().await.map_err(to_string)?).await;
+            print_items(&items, json);
+        }
+
+        Command::Put { paths } => {
+            trash_operations::put_items(paths.clone())
+                .await
+                .map_err(to_string)?;
+            if json {
+                print_json(&paths);
+            } else {
+                for path in &paths {
+                    println!("Trashed {}", path.display());
+                }
+            }
+        }
+
+        Command::Restore { items } => {
+            let selected = select_items(&items).await?;
+            let restored = trash_operations::restore_items(selected)
+                .await
+                .map_err(to_string)?;
+            if json {
+                print_json(&restored);
+            } else {
+                for path in &restored {
+                    println!("Restored {}", path.display());
+                }
+            }
+        }
+
+        Command::Purge { items } => {
+            let selected = select_items(&items).await?;
+            let purged = enrich(selected.clone()).await;
+            delete(selected, &Config::load()).await.map_err(to_string)?;
+            print_items(&purged, json);
+        }
+
+        Command::Empty => {
+            let config = Config::load();
+            let result = if config.secure_erase {
+                trash_operations::secure_empty_trash(
+                    config.pinned_items,
+                    config.secure_erase_passes,
+                    |_| {},
+                )
+                .await
+            } else {
+                trash_operations::empty_trash(config.pinned_items).await
+            };
+            if let Err(e) = result {
+                if let TrashError::PartialFailure { failed } = &e {
+                    for failure in failed {
+                        eprintln!(
+                            "  {}: {}",
+                            failure.item.name.to_string_lossy(),
+                            failure.error
+                        );
+                    }
+                }
+                return Err(e.to_string());
+            }
+            if json {
+                // What is left (pinned items)
+                let status = tokio::task::spawn_blocking(TrashStatus::check)
+                    .await
+                    .map_err(to_string)?;
+                print_json(&status);
+            } else {
+                println!("Trash emptied");
+            }
+        }
+
+        Command::Status => {
+            let status = tokio::task::spawn_blocking(TrashStatus::check)
+                .await
+                .map_err(to_string)?;
+            if json {
+                print_json(&status);
+            } else if status.is_empty {
+                println!("Trash is empty");
+            } else {
+                println!("{} items in trash", status.item_count);
+            }
+        }
+
+        Command::Prune {
+            older_than,
+            dry_run,
+        } => {
+            let cutoff = chrono::Utc::now()
+                .timestamp()
+                .checked_sub(older_than)
+                .ok_or_else(|| format!("--older-than is out of range: {older_than}s"))?;
+            let config = Config::load();
+            let policy = CleanupPolicy::from_config(&config);
+            let expired: Vec<trash::TrashItem> = trash_operations::list_items()
+                .await
+                .map_err(to_string)?
+                .into_iter()
+                .filter(|item| item.time_deleted < cutoff && !policy.is_protected(item))
+                .collect();
+            if !dry_run && !expired.is_empty() {
+                delete(expired.clone(), &config).await.map_err(to_string)?;
+            }
+            print_items(&enrich(expired).await, json);
+        }
+
+        Command::Export { format, output } => {
+            let items = enrich(trash_operations::list_items().await.map_err(to_string)?).await;
+            let format = if format == "csv" {
+                ExportFormat::Csv
+            } else {
+                ExportFormat::Json
+            };
+
+            let units = Config::load().size_units;
+
+            match output {
+                Some(path) => {
+                    let path = trash_export::export_to_file(items, format, units, path).await?;
+                    println!("Exported to {}", path.display());
+                }
+                None => print!("{}", trash_export::to_string(&items, format, units)?),
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Resolves each query to exactly one trash item (id, then name, then original path)
+async fn select_items(queries: &[String]) -> Result<Vec<trash::TrashItem>, String> {
+    let all = trash_operations::list_items().await.map_err(to_string)?;
+    let mut selected = Vec::with_capacity(queries.len());
+
+    for query in queries {
+        let by_id: Vec<&trash::TrashItem> = all
+            .iter()
+            .filter(|item| item.id.to_string_lossy() == query.as_str())
+            .collect();
+        let matches: Vec<&trash::TrashItem> = if by_id.is_empty() {
+            all.iter()
+                .filter(|item| {
+                    item.name.to_string_lossy() == query.as_str()
+                        || item.original_path().to_string_lossy() == query.as_str()
+                })
+                .collect()
+        } else {
+            by_id
+        };
+
+        match matches.as_slice() {
+            [] => return Err(format!("{query}: not found in trash")),
+            [item] => selected.push((*item).clone()),
+            _ => {
+                let ids: Vec<String> = matches
+                    .iter()
+                    .map(|item| item.id.to_string_lossy().into_owned())
+                    .collect();
+                return Err(format!(
+                    "{query}: matches {} items, use an id:\n  {}",
+                    matches.len(),
+                    ids.join("\n  ")
+                ));
+            }
+        }
+    }
+
+    Ok(selected)
+}
+
+/// Permanently deletes `items`, securely erased if `Config::secure_erase`
+async fn delete(items: Vec<trash::TrashItem>, config: &Config) -> Result<(), TrashError> {
+    if config.secure_erase {
+        trash_operations::secure_delete_items(items, config.secure_erase_passes, |_| {}).await
+    } else {
+        trash_operations::delete_items(items).await
+    }
+}
+
+/// Enriches items (size, MIME) off the async runtime, sorted like the popup
+async fn enrich(items: Vec<trash::TrashItem>) -> Vec<EnrichedTrashItem> {
+    tokio::task::spawn_blocking(move || {
+        let mut enriched: Vec<EnrichedTrashItem> = items
+            .into_iter()
+            .map(EnrichedTrashItem::from_trash_item)
+            .collect();
+        EnrichedTrashItem::sort_items(&mut enriched, true);
+        enriched
+    })
+    .await
+    .unwrap_or_default()
+}
+
+fn print_items(items: &[EnrichedTrashItem], json: bool) {
+    let records: Vec<ExportRecord> = trash_export::records(items, Config::load().size_units);
+
+    if json {
+        print_json(&records);
+        return;
+    }
+
+    for item in &records {
+        let deleted = chrono::DateTime::from_timestamp(item.time_deleted, 0)
+            .map(|time| {
+                time.with_timezone(&chrono::Local)
+                    .format("%Y-%m-%d %H:%M")
+                    .to_string()
+            })
+            .unwrap_or_default();
+        println!(
+            "{deleted}  {:>10}  {}  ({})",
+            item.size, item.name, item.original_path
+        );
+    }
+}
+
+fn print_json<T: Serialize + ?Sized>(value: &T) {
+    match serde_json::to_string_pretty(value) {
+        Ok(json) => println!("{json}"),
+        Err(e) => eprintln!("Failed to serialize output: {e}"),
+    }
+}
+
+fn to_string(e: impl std::fmt::Display) -> String {
+    e.to_string()
+}
+
+/// Parses `30d`, `2w`, `12h`, `45m`, `90s` into seconds
+fn parse_age(value: &str) -> Result<i64, String> {
+    let value = value.trim();
+    let split = value
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(value.len());
+    let (number, unit) = value.split_at(split);
+
+    let number: i64 = number
+        .parse()
+        .map_err(|_| format!("invalid duration: {value}"))?;
+    let multiplier = match unit {
+        "s" => 1,
+        "m" => 60,
+        "h" => 60 * 60,
+        "" | "d" => 24 * 60 * 60,
+        "w" => 7 * 24 * 60 * 60,
+        _ => {
+            return Err(format!(
+                "invalid duration unit '{unit}' (use s, m, h, d or w)"
+            ));
+        }
+    };
+
+    number
+        .checked_mul(multiplier)
+        .ok_or_else(|| format!("duration too large: {value}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::CommandFactory;
+    use clap::error::ErrorKind;
+
+    fn parse_args(args: &[&str]) -> Option<Result<Cli, clap::Error>> {
+        parse(
+            std::iter::once("cosmic-applet-trash")
+                .chain(args.iter().copied())
+                .map(OsString::from),
+        )
+    }
+
+    #[test]
+    fn cli_definition_is_valid() {
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn no_arguments_start_the_applet() {
+        assert!(parse_args(&[]).is_none());
+    }
+
+    #[test]
+    fn global_flags_before_the_subcommand_use_the_cli() {
+        let cli = parse_args(&["--json", "list"]).unwrap().unwrap();
+        assert!(cli.json);
+        assert!(matches!(cli.command, Command::List));
+
+        let cli = parse_args(&["empty", "--json"]).unwrap().unwrap();
+        assert!(cli.json);
+        assert!(matches!(cli.command, Command::Empty));
+    }
+
+    #[test]
+    fn help_and_version_are_handled_by_clap() {
+        let version = parse_args(&["--version"]).unwrap().unwrap_err();
+        assert_eq!(version.kind(), ErrorKind::DisplayVersion);
+        assert_eq!(version.exit_code(), 0);
+
+        let help = parse_args(&["--help"]).unwrap().unwrap_err();
+        assert_eq!(help.kind(), ErrorKind::DisplayHelp);
+        assert_eq!(help.exit_code(), 0);
+    }
+
+    #[test]
+    fn unknown_arguments_are_errors() {
+        let error = parse_args(&["frobnicate"]).unwrap().unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidSubcommand);
+        assert_ne!(error.exit_code(), 0);
+
+        assert!(parse_args(&["put"]).unwrap().is_err());
+    }
+
+    #[test]
+    fn prune_parses_age() {
+        let cli = parse_args(&["prune", "--older-than", "2w", "--dry-run"])
+            .unwrap()
+            .unwrap();
+        assert!(matches!(
+            cli.command,
+            Command::Prune {
+                older_than: 1_209_600,
+                dry_run: true
+            }
+        ));
+    }
+
+    #[test]
+    fn parse_age_units() {
+        assert_eq!(parse_age("90s"), Ok(90));
+        assert_eq!(parse_age("45m"), Ok(45 * 60));
+        assert_eq!(parse_age("12h"), Ok(12 * 60 * 60));
+        assert_eq!(parse_age("30"), Ok(30 * 24 * 60 * 60));
+        assert_eq!(parse_age("30d"), Ok(30 * 24 * 60 * 60));
+        assert!(parse_age("3y").is_err());
+        assert!(parse_age("d").is_err());
+        assert!(parse_age("-1d").is_err());
+        assert!(parse_age(&format!("{}w", i64::MAX)).is_err());
+    }
+}
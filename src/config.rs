@@ -1,10 +1,293 @@
 // SPDX-License-Identifier: GPL-3.0-only
 
+use crate::app::AppModel;
+use crate::cleanup_rules::CleanupRule;
+use crate::file_manager::FileManager;
+use crate::fl;
+use crate::size_format::SizeUnits;
 use cosmic::cosmic_config::{self, CosmicConfigEntry, cosmic_config_derive::CosmicConfigEntry};
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Default, Clone, CosmicConfigEntry, Eq, PartialEq)]
+#[derive(Debug, Clone, CosmicConfigEntry, Eq, PartialEq)]
 #[version = 1]
 pub struct Config {
-    // [FASE 1+] Adicionar opções de configuração
-    // pub auto_empty_days: Option<u32>,
+    // Items list
+    /// Row spacing and icon size
+    pub list_density: ListDensity,
+    /// Sort order when the applet starts (folders always first)
+    pub default_sort: SortOrder,
+    /// Most recently deleted items shown above the list (0 hides the strip)
+    pub recent_count: u32,
+    /// Size display: IEC (KiB, powers of 1024) or SI (kB, powers of 1000)
+    pub size_units: SizeUnits,
+
+    // Panel icon
+    /// Overlay on the panel icon: item count badge or fill level
+    pub panel_indicator: PanelIndicator,
+    /// Trash size (MiB) the fill level indicator counts as full
+    pub quota_mb: u64,
+    /// Middle click on the panel icon
+    pub middle_click: PanelAction,
+    /// Right click on the panel icon
+    pub right_click: PanelAction,
+    /// Scrolling over the panel icon cycles through recent items in the tooltip
+    pub scroll_recent: bool,
+
+    // Confirmation prompts
+    /// Ask before "Empty trash"
+    pub confirm_empty: bool,
+    /// Ask before deleting a single item
+    pub confirm_delete: bool,
+
+    /// Open trashed files from a read-only temp copy instead of in place
+    pub open_read_only: bool,
+    /// File manager used for "Open trash" and "Show original location"
+    pub file_manager: FileManager,
+    /// Command template for `FileManager::Custom` (`{uri}` / `{path}` placeholders)
+    pub custom_file_manager: String,
+
+    // Desktop notifications (per event type)
+    /// Notify when "Empty trash" completes
+    pub notify_empty: bool,
+    /// Notify when cleanup policies purge items automatically
+    pub notify_auto_purge: bool,
+    /// Notify when an item is restored (with "Undo")
+    pub notify_restore: bool,
+    /// Notify when restoring an item fails
+    pub notify_restore_failure: bool,
+    /// Notify when the trash grows past this many MiB (0 disables)
+    pub size_threshold_mb: u64,
+
+    // Secure erase (see `secure_erase` for SSD / copy-on-write caveats)
+    /// Overwrite file contents before "Delete" and "Empty trash" unlink them
+    pub secure_erase: bool,
+    /// Overwrite passes (clamped by `secure_erase::effective_passes`)
+    pub secure_erase_passes: u32,
+
+    /// Pinned items (`.trashinfo` paths): never emptied or purged by cleanup
+    pub pinned_items: Vec<String>,
+
+    // Automatic cleanup (see `cleanup_rules`)
+    /// Days after which items are purged automatically (0 disables)
+    pub retention_days: u32,
+    /// Glob/regex rules: keep matches or purge them immediately
+    pub cleanup_rules: Vec<CleanupRule>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            list_density: ListDensity::default(),
+            default_sort: SortOrder::default(),
+            recent_count: 3,
+            size_units: SizeUnits::default(),
+            panel_indicator: PanelIndicator::default(),
+            quota_mb: 1024,
+            middle_click: PanelAction::OpenTrash,
+            right_click: PanelAction::ContextMenu,
+            scroll_recent: true,
+            // Permanent deletion asks first unless turned off
+            confirm_empty: true,
+            confirm_delete: true,
+            open_read_only: false,
+            file_manager: FileManager::default(),
+            custom_file_manager: String::new(),
+            notify_empty: false,
+            notify_auto_purge: false,
+            notify_restore: false,
+            notify_restore_failure: false,
+            size_threshold_mb: 0,
+            secure_erase: false,
+            secure_erase_passes: crate::secure_erase::DEFAULT_PASSES,
+            pinned_items: Vec::new(),
+            retention_days: 0,
+            cleanup_rules: Vec::new(),
+        }
+    }
+}
+
+/// Items list density
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ListDensity {
+    #[default]
+    Comfortable,
+    Compact,
+}
+
+impl ListDensity {
+    pub const ALL: [Self; 2] = [Self::Comfortable, Self::Compact];
+
+    #[must_use]
+    pub fn name(self) -> String {
+        match self {
+            Self::Comfortable => fl!("density-comfortable"),
+            Self::Compact => fl!("density-compact"),
+        }
+    }
+}
+
+/// Initial items list order
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SortOrder {
+    /// A-Z
+    #[default]
+    NameAscending,
+    /// Z-A
+    NameDescending,
+}
+
+impl SortOrder {
+    pub const ALL: [Self; 2] = [Self::NameAscending, Self::NameDescending];
+
+    #[must_use]
+    pub fn name(self) -> String {
+        match self {
+            Self::NameAscending => fl!("sort-name-ascending"),
+            Self::NameDescending => fl!("sort-name-descending"),
+        }
+    }
+
+    #[must_use]
+    pub const fn is_ascending(self) -> bool {
+        matches!(self, Self::NameAscending)
+    }
+}
+
+/// Overlay drawn on the panel icon
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PanelIndicator {
+    /// Plain empty/full icon
+    #[default]
+    None,
+    /// Badge with the number of items
+    ItemCount,
+    /// Bar showing the trash size relative to `Config::quota_mb`
+    FillLevel,
+}
+
+impl PanelIndicator {
+    pub const ALL: [Self; 3] = [Self::None, Self::ItemCount, Self::FillLevel];
+
+    #[must_use]
+    pub fn name(self) -> String {
+        match self {
+            Self::None => fl!("indicator-none"),
+            Self::ItemCount => fl!("indicator-item-count"),
+            Self::FillLevel => fl!("indicator-fill-level"),
+        }
+    }
+}
+
+/// What a middle or right click on the panel icon does
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PanelAction {
+    #[default]
+    Nothing,
+    /// Open or close the popup, like a left click
+    TogglePopup,
+    /// Compact menu: Empty, Open, Settings
+    ContextMenu,
+    /// Open the trash in the file manager
+    OpenTrash,
+}
+
+impl PanelAction {
+    pub const ALL: [Self; 4] = [
+        Self::Nothing,
+        Self::TogglePopup,
+        Self::ContextMenu,
+        Self::OpenTrash,
+    ];
+
+    #[must_use]
+    pub fn name(self) -> String {
+        match self {
+            Self::Nothing => fl!("panel-action-nothing"),
+            Self::TogglePopup => fl!("panel-action-popup"),
+            Self::ContextMenu => fl!("panel-action-context-menu"),
+            Self::OpenTrash => fl!("panel-action-open-trash"),
+        }
+    }
+}
+
+/// A single edit from the settings page
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SettingChange {
+    ListDensity(ListDensity),
+    DefaultSort(SortOrder),
+    RecentCount(u32),
+    SizeUnits(SizeUnits),
+    PanelIndicator(PanelIndicator),
+    QuotaMb(u64),
+    MiddleClick(PanelAction),
+    RightClick(PanelAction),
+    ScrollRecent(bool),
+    ConfirmEmpty(bool),
+    ConfirmDelete(bool),
+    OpenReadOnly(bool),
+    FileManager(FileManager),
+    CustomFileManager(String),
+    NotifyEmpty(bool),
+    NotifyAutoPurge(bool),
+    NotifyRestore(bool),
+    NotifyRestoreFailure(bool),
+    SizeThresholdMb(u64),
+    SecureErase(bool),
+    SecureErasePasses(u32),
+    RetentionDays(u32),
+}
+
+impl Config {
+    /// Applies a settings page edit
+    pub fn apply(&mut self, change: SettingChange) {
+        match change {
+            SettingChange::ListDensity(density) => self.list_density = density,
+            SettingChange::DefaultSort(order) => self.default_sort = order,
+            SettingChange::RecentCount(value) => self.recent_count = value,
+            SettingChange::SizeUnits(units) => self.size_units = units,
+            SettingChange::PanelIndicator(indicator) => self.panel_indicator = indicator,
+            SettingChange::QuotaMb(value) => self.quota_mb = value,
+            SettingChange::MiddleClick(action) => self.middle_click = action,
+            SettingChange::RightClick(action) => self.right_click = action,
+            SettingChange::ScrollRecent(value) => self.scroll_recent = value,
+            SettingChange::ConfirmEmpty(value) => self.confirm_empty = value,
+            SettingChange::ConfirmDelete(value) => self.confirm_delete = value,
+            SettingChange::OpenReadOnly(value) => self.open_read_only = value,
+            SettingChange::FileManager(file_manager) => self.file_manager = file_manager,
+            SettingChange::CustomFileManager(template) => self.custom_file_manager = template,
+            SettingChange::NotifyEmpty(value) => self.notify_empty = value,
+            SettingChange::NotifyAutoPurge(value) => self.notify_auto_purge = value,
+            SettingChange::NotifyRestore(value) => self.notify_restore = value,
+            SettingChange::NotifyRestoreFailure(value) => self.notify_restore_failure = value,
+            SettingChange::SizeThresholdMb(value) => self.size_threshold_mb = value,
+            SettingChange::SecureErase(value) => self.secure_erase = value,
+            SettingChange::SecureErasePasses(value) => self.secure_erase_passes = value,
+            SettingChange::RetentionDays(value) => self.retention_days = value,
+        }
+    }
+
+    /// Reads the applet config (defaults if missing or unreadable)
+    ///
+    /// For code paths without a running applet (CLI, D-Bus service calls).
+    #[must_use]
+    pub fn load() -> Self {
+        cosmic_config::Config::new(<AppModel as cosmic::Application>::APP_ID, Self::VERSION)
+            .map(|context| match Self::get_entry(&context) {
+                Ok(config) | Err((_, config)) => config,
+            })
+            .unwrap_or_default()
+    }
+
+    /// Whether `item` is pinned
+    #[must_use]
+    pub fn is_pinned(&self, item: &trash::TrashItem) -> bool {
+        is_pinned(&self.pinned_items, item)
+    }
+}
+
+/// Whether `item` (by `.trashinfo` path) is in `pinned`
+#[must_use]
+pub fn is_pinned(pinned: &[String], item: &trash::TrashItem) -> bool {
+    let id = item.id.to_string_lossy();
+    pinned.iter().any(|pinned| pinned.as_str() == id)
 }
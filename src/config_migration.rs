@@ -0,0 +1,360 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Config schema migration
+//!
+//! `cosmic_config` keeps one directory per schema version
+//! (`~/.config/cosmic/<app id>/v<N>/<key>`, one RON value per file). When the
+//! current version has no directory yet, the newest older one is copied
+//! forward, step by step, renaming, converting or dropping keys. The old
+//! directory is left untouched so downgrades keep working.
+
+use crate::app::AppModel;
+use crate::config::Config;
+use cosmic::cosmic_config::CosmicConfigEntry;
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Raw config entries: key → RON value
+type Keys = BTreeMap<String, String>;
+
+/// One schema step: migrates keys from `version - 1` to `version`
+#[allow(dead_code)] // Built by the first schema step
+struct Step {
+    version: u64,
+    migrate: fn(&mut Keys, &mut Vec<String>),
+}
+
+/// Schema steps, oldest first
+///
+/// Empty: version 1 is the first schema with any keys. When a release changes
+/// a key, bump `Config`'s `#[version]` and add a step built from `convert`,
+/// `remove` and `set`.
+const STEPS: &[Step] = &[];
+
+/// Migrates the applet's config to `Config::VERSION` (see module docs)
+///
+/// Failures are logged; the applet then starts with defaults for missing keys.
+pub fn run() {
+    let app_id = <AppModel as cosmic::Application>::APP_ID;
+    let Some(app_dir) = config_home().map(|home| home.join("cosmic").join(app_id)) else {
+        return;
+    };
+
+    match migrate_dir(&app_dir, Config::VERSION, STEPS) {
+        Ok(changes) => {
+            for change in changes {
+                eprintln!("Config migration: {change}");
+            }
+        }
+        Err(e) => eprintln!("Config migration failed in {}: {e}", app_dir.display()),
+    }
+}
+
+/// Migrates `app_dir/v<old>` into `app_dir/v<current>` through `steps`,
+/// returning what changed
+///
+/// Does nothing if `v<current>` exists or there is no older version.
+fn migrate_dir(app_dir: &Path, current: u64, steps: &[Step]) -> io::Result<Vec<String>> {
+    let target = version_dir(app_dir, current);
+    if target.exists() {
+        return Ok(Vec::new());
+    }
+
+    let Some(from) = newest_older_version(app_dir, current)? else {
+        return Ok(Vec::new()); // Fresh install
+    };
+
+    let mut keys = read_keys(&version_dir(app_dir, from))?;
+    let mut log = vec![format!("v{from} → v{current}")];
+
+    for step in steps
+        .iter()
+        .filter(|step| step.version > from && step.version <= current)
+    {
+        (step.migrate)(&mut keys, &mut log);
+    }
+
+    fs::create_dir_all(&target)?;
+    for (key, value) in &keys {
+        fs::write(target.join(key), value)?;
+    }
+
+    Ok(log)
+}
+
+fn version_dir(app_dir: &Path, version: u64) -> PathBuf {
+    app_dir.join(format!("v{version}"))
+}
+
+fn newest_older_version(app_dir: &Path, current: u64) -> io::Result<Option<u64>> {
+    let entries = match fs::read_dir(app_dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e),
+    };
+
+    Ok(entries
+        .filter_map(Result::ok)
+        .filter(|entry| entry.path().is_dir())
+        .filter_map(|entry| {
+            entry
+                .file_name()
+                .to_str()?
+                .strip_prefix('v')?
+                .parse::<u64>()
+                .ok()
+        })
+        .filter(|version| *version < current)
+        .max())
+}
+
+fn read_keys(dir: &Path) -> io::Result<Keys> {
+    let mut keys = Keys::new();
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        if !entry.path().is_file() {
+            continue;
+        }
+        let Some(key) = entry.file_name().to_str().map(str::to_string) else {
+            continue;
+        };
+        // Skip editor/atomic-write leftovers
+        if key.starts_with('.') {
+            continue;
+        }
+        keys.insert(key, fs::read_to_string(entry.path())?);
+    }
+    Ok(keys)
+}
+
+/// Renames `from` to `to`, converting the value (dropped if it cannot be converted)
+#[allow(dead_code)] // Step helper
+fn convert(
+    keys: &mut Keys,
+    log: &mut Vec<String>,
+    from: &str,
+    to: &str,
+    convert: impl Fn(&str) -> Option<String>,
+) {
+    let Some(value) = keys.remove(from) else {
+        return;
+    };
+
+    match convert(&value) {
+        Some(converted) => {
+            log.push(format!("{from} = {} → {to} = {converted}", value.trim()));
+            keys.insert(to.to_string(), converted);
+        }
+        None => log.push(format!(
+            "dropped {from} = {} (unrecognized value)",
+            value.trim()
+        )),
+    }
+}
+
+/// Removes a key that no longer exists, returning its value
+#[allow(dead_code)] // Step helper
+fn remove(keys: &mut Keys, log: &mut Vec<String>, key: &str) -> Option<String> {
+    let value = keys.remove(key)?;
+    log.push(format!("removed {key} = {}", value.trim()));
+    Some(value)
+}
+
+#[allow(dead_code)] // Step helper
+fn set(keys: &mut Keys, log: &mut Vec<String>, key: &str, value: &str) {
+    log.push(format!("set {key} = {value}"));
+    keys.insert(key.to_string(), value.to_string());
+}
+
+/// `$XDG_CONFIG_HOME`, or `~/.config`
+fn config_home() -> Option<PathBuf> {
+    std::env::var_os("XDG_CONFIG_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| Path::new(&home).join(".config")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Fixture schema: v2 turns `descending` into `order`, drops `legacy` and
+    /// makes a zero `passes` explicit; v3 renames `order` to `sort`
+    const TEST_STEPS: &[Step] = &[
+        Step {
+            version: 2,
+            migrate: to_v2,
+        },
+        Step {
+            version: 3,
+            migrate: to_v3,
+        },
+    ];
+
+    fn to_v2(keys: &mut Keys, log: &mut Vec<String>) {
+        convert(keys, log, "descending", "order", |value| {
+            match value.trim() {
+                "true" => Some("Descending".to_string()),
+                "false" => Some("Ascending".to_string()),
+                _ => None,
+            }
+        });
+        remove(keys, log, "legacy");
+        if keys
+            .get("passes")
+            .is_some_and(|passes| passes.trim() == "0")
+        {
+            set(keys, log, "passes", "3");
+        }
+    }
+
+    fn to_v3(keys: &mut Keys, log: &mut Vec<String>) {
+        convert(keys, log, "order", "sort", |value| {
+            Some(value.trim().to_string())
+        });
+    }
+
+    /// Writes `keys` into `app_dir/v<version>`
+    fn write_version(app_dir: &Path, version: u64, keys: &[(&str, &str)]) {
+        let dir = version_dir(app_dir, version);
+        fs::create_dir_all(&dir).unwrap();
+        for (key, value) in keys {
+            fs::write(dir.join(key), value).unwrap();
+        }
+    }
+
+    fn read_version(app_dir: &Path, version: u64) -> Keys {
+        read_keys(&version_dir(app_dir, version)).unwrap()
+    }
+
+    fn keys(entries: &[(&str, &str)]) -> Keys {
+        entries
+            .iter()
+            .map(|(key, value)| ((*key).to_string(), (*value).to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn step_converts_removes_and_sets_keys() {
+        let app_dir = tempfile::tempdir().unwrap();
+        let v1 = [
+            ("descending", "true"),
+            ("legacy", "false"),
+            ("passes", "0"),
+            ("confirm_empty", "false"),
+            (".confirm_empty.swp", "junk"),
+        ];
+        write_version(app_dir.path(), 1, &v1);
+
+        let log = migrate_dir(app_dir.path(), 2, TEST_STEPS).unwrap();
+
+        assert_eq!(
+            log,
+            [
+                "v1 → v2",
+                "descending = true → order = Descending",
+                "removed legacy = false",
+                "set passes = 3",
+            ]
+        );
+        assert_eq!(
+            read_version(app_dir.path(), 2),
+            keys(&[
+                ("order", "Descending"),
+                ("passes", "3"),
+                ("confirm_empty", "false"),
+            ])
+        );
+        // The old version stays for downgrades
+        assert_eq!(read_version(app_dir.path(), 1), keys(&v1[..4]));
+    }
+
+    #[test]
+    fn steps_run_in_order_from_the_newest_older_version() {
+        let app_dir = tempfile::tempdir().unwrap();
+        write_version(app_dir.path(), 1, &[("descending", "true")]);
+        write_version(
+            app_dir.path(),
+            2,
+            &[("order", "Ascending\n"), ("legacy", "true")],
+        );
+
+        let log = migrate_dir(app_dir.path(), 3, TEST_STEPS).unwrap();
+
+        // Only v2 → v3 runs: `legacy` survives because the v2 step is skipped
+        assert_eq!(log, ["v2 → v3", "order = Ascending → sort = Ascending"]);
+        assert_eq!(
+            read_version(app_dir.path(), 3),
+            keys(&[("sort", "Ascending"), ("legacy", "true")])
+        );
+    }
+
+    #[test]
+    fn chained_steps_carry_keys_through_every_version() {
+        let app_dir = tempfile::tempdir().unwrap();
+        write_version(app_dir.path(), 1, &[("descending", "false\n")]);
+
+        migrate_dir(app_dir.path(), 3, TEST_STEPS).unwrap();
+
+        assert_eq!(
+            read_version(app_dir.path(), 3),
+            keys(&[("sort", "Ascending")])
+        );
+        assert!(!version_dir(app_dir.path(), 2).exists());
+    }
+
+    #[test]
+    fn unconvertible_values_are_dropped() {
+        let app_dir = tempfile::tempdir().unwrap();
+        write_version(app_dir.path(), 1, &[("descending", "maybe")]);
+
+        let log = migrate_dir(app_dir.path(), 2, TEST_STEPS).unwrap();
+
+        assert!(read_version(app_dir.path(), 2).is_empty());
+        assert!(
+            log.iter()
+                .any(|line| line.starts_with("dropped descending"))
+        );
+    }
+
+    #[test]
+    fn existing_current_version_is_left_alone() {
+        let app_dir = tempfile::tempdir().unwrap();
+        write_version(app_dir.path(), 1, &[("descending", "true")]);
+        write_version(app_dir.path(), 2, &[("order", "Ascending")]);
+
+        assert!(
+            migrate_dir(app_dir.path(), 2, TEST_STEPS)
+                .unwrap()
+                .is_empty()
+        );
+        assert_eq!(
+            read_version(app_dir.path(), 2),
+            keys(&[("order", "Ascending")])
+        );
+    }
+
+    #[test]
+    fn missing_config_dir_is_a_fresh_install() {
+        let root = tempfile::tempdir().unwrap();
+        let app_dir = root.path().join("com.example.Missing");
+
+        assert!(migrate_dir(&app_dir, 2, TEST_STEPS).unwrap().is_empty());
+        assert!(!app_dir.exists());
+    }
+
+    #[test]
+    fn newer_versions_are_ignored() {
+        let app_dir = tempfile::tempdir().unwrap();
+        write_version(app_dir.path(), 3, &[("sort", "Descending")]);
+
+        assert!(
+            migrate_dir(app_dir.path(), 2, TEST_STEPS)
+                .unwrap()
+                .is_empty()
+        );
+        assert!(!version_dir(app_dir.path(), 2).exists());
+    }
+}
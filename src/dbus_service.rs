@@ -0,0 +1,203 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Backend: Session-bus interface for other desktop components
+//!
+//! Exposes the applet's trash backend as `com.github.thiagocys.CosmicAppletTrash`
+//! so scripts and other applets can query and drive the trash through one process.
+//!
+//! The bus name has a single owner. When several instances run (panel and
+//! dock), the first one owns it and the others queue behind it; the bus hands
+//! the name to the next instance when the owner exits.
+//!
+//! ```text
+//! busctl --user call com.github.thiagocys.CosmicAppletTrash \
+//!     /com/github/thiagocys/CosmicAppletTrash \
+//!     com.github.thiagocys.CosmicAppletTrash GetStatus
+//! ```
+
+use crate::config::Config;
+use crate::error::TrashError;
+use crate::trash_item_metadata::EnrichedTrashItem;
+use crate::trash_operations;
+use crate::trash_status::TrashStatus;
+use zbus::fdo;
+use zbus::object_server::SignalEmitter;
+
+/// Well-known bus name (same as the applet id)
+pub const BUS_NAME: &str = "com.github.thiagocys.CosmicAppletTrash";
+/// Object path of the trash interface
+pub const OBJECT_PATH: &str = "/com/github/thiagocys/CosmicAppletTrash";
+
+/// D-Bus representation of a trash item:
+/// (id, name, original path, deletion time as Unix seconds, size display)
+pub type DbusTrashItem = (String, String, String, i64, String);
+
+/// `com.github.thiagocys.CosmicAppletTrash` interface
+///
+/// Methods delegate to `trash_operations`; the applet's filesystem watcher
+/// picks up the resulting changes like any other trash modification.
+pub struct TrashService;
+
+#[zbus::interface(name = "com.github.thiagocys.CosmicAppletTrash")]
+impl TrashService {
+    /// Lists all trash items
+    async fn list_items(&self) -> fdo::Result<Vec<DbusTrashItem>> {
+        let items = trash_operations::list_items().await.map_err(to_fdo)?;
+        let units = Config::load().size_units;
+
+        tokio::task::spawn_blocking(move || {
+            items
+                .into_iter()
+                .map(EnrichedTrashItem::from_trash_item)
+                .map(|enriched| {
+                    (
+                        enriched.item.id.to_string_lossy().into_owned(),
+                        enriched.item.name.to_string_lossy().into_owned(),
+                        enriched.item.original_path().to_string_lossy().into_owned(),
+                        enriched.item.time_deleted,
+                        enriched.size_display(units),
+                    )
+                })
+                .collect()
+        })
+        .await
+        .map_err(|e| fdo::Error::Failed(format!("Task spawn failed: {e}")))
+    }
+
+    /// Restores items by id, returning their restored paths
+    async fn restore(&self, ids: Vec<String>) -> fdo::Result<Vec<String>> {
+        let items = find_all(ids).await?;
+        let paths = trash_operations::restore_items(items)
+            .await
+            .map_err(to_fdo)?;
+
+        Ok(paths
+            .iter()
+            .map(|path| path.to_string_lossy().into_owned())
+            .collect())
+    }
+
+    /// Permanently deletes items by id (securely erased if `Config::secure_erase`)
+    async fn purge(&self, ids: Vec<String>) -> fdo::Result<()> {
+        let items = find_all(ids).await?;
+        let config = Config::load();
+
+        if config.secure_erase {
+            trash_operations::secure_delete_items(items, config.secure_erase_passes, |_| {}).await
+        } else {
+            trash_operations::delete_items(items).await
+        }
+        .map_err(to_fdo)
+    }
+
+    /// Permanently deletes every item in the trash except pinned ones
+    /// (securely erased if `Config::secure_erase`)
+    async fn empty(&self) -> fdo::Result<()> {
+        let config = Config::load();
+
+        if config.secure_erase {
+            trash_operations::secure_empty_trash(
+                config.pinned_items,
+                config.secure_erase_passes,
+                |_| {},
+            )
+            .await
+        } else {
+            trash_operations::empty_trash(config.pinned_items).await
+        }
+        .map_err(to_fdo)
+    }
+
+    /// Returns (is_empty, item_count)
+    async fn get_status(&self) -> fdo::Result<(bool, u32)> {
+        let status = tokio::task::spawn_blocking(TrashStatus::check)
+            .await
+            .map_err(|e| fdo::Error::Failed(format!("Task spawn failed: {e}")))?;
+
+        Ok(status_args(&status))
+    }
+
+    /// Emitted whenever the applet observes a trash change
+    #[zbus(signal)]
+    async fn status_changed(
+        emitter: &SignalEmitter<'_>,
+        is_empty: bool,
+        item_count: u32,
+    ) -> zbus::Result<()>;
+}
+
+/// Resolves all ids, failing with `InvalidArgs` if any is unknown
+async fn find_all(ids: Vec<String>) -> fdo::Result<Vec<trash::TrashItem>> {
+    let requested = ids.len();
+    let items = trash_operations::find_items(ids).await.map_err(to_fdo)?;
+
+    if items.len() == requested {
+        Ok(items)
+    } else {
+        Err(fdo::Error::InvalidArgs(format!(
+            "{} of {requested} ids not found in trash",
+            requested - items.len()
+        )))
+    }
+}
+
+fn to_fdo(e: TrashError) -> fdo::Error {
+    match e {
+        TrashError::PermissionDenied { .. } => fdo::Error::AccessDenied(e.to_string()),
+        TrashError::NotFound { .. } => fdo::Error::FileNotFound(e.to_string()),
+        TrashError::Conflict { .. } => fdo::Error::FileExists(e.to_string()),
+        _ => fdo::Error::Failed(e.to_string()),
+    }
+}
+
+fn status_args(status: &TrashStatus) -> (bool, u32) {
+    (
+        status.is_empty,
+        u32::try_from(status.item_count).unwrap_or(u32::MAX),
+    )
+}
+
+/// Serves the interface on the session bus and claims the bus name
+///
+/// If another instance owns the name, this one is queued and takes over when
+/// that instance exits (see module docs).
+///
+/// # Errors
+///
+/// Returns error if the session bus is unreachable or the name request fails
+pub async fn serve() -> zbus::Result<zbus::Connection> {
+    let connection = zbus::connection::Builder::session()?
+        .serve_at(OBJECT_PATH, TrashService)?
+        .build()
+        .await?;
+
+    // `Builder::name` never queues, so request the name ourselves. Without
+    // `DoNotQueue` we wait in line; without `ReplaceExisting` (part of the
+    // default flags) we do not take the name from the running instance.
+    let reply = connection
+        .request_name_with_flags(BUS_NAME, fdo::RequestNameFlags::AllowReplacement.into())
+        .await?;
+    if reply == fdo::RequestNameReply::InQueue {
+        eprintln!("{BUS_NAME} is owned by another instance; queued until it exits");
+    }
+
+    Ok(connection)
+}
+
+/// Emits `StatusChanged` from the served interface
+///
+/// # Errors
+///
+/// Returns error if the interface is not served on `connection` or emission fails
+pub async fn emit_status_changed(
+    connection: zbus::Connection,
+    status: TrashStatus,
+) -> zbus::Result<()> {
+    let iface = connection
+        .object_server()
+        .interface::<_, TrashService>(OBJECT_PATH)
+        .await?;
+    let (is_empty, item_count) = status_args(&status);
+
+    TrashService::status_changed(iface.signal_emitter(), is_empty, item_count).await
+}
@@ -0,0 +1,460 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Native integration: open trashed files in their default application
+//!
+//! Resolves the default handler for a MIME type from the XDG `mimeapps.list` files
+//! (Ref: freedesktop.org "Association between MIME types and applications" spec)
+//! and launches it with `std::process::Command`, without restoring the item.
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Opens a trashed item in place with its default application
+///
+/// When `read_only` is true, the item is first copied to a private temp
+/// directory with write permissions removed, so the application cannot modify
+/// (or save over) the copy kept in the trash.
+///
+/// # Errors
+///
+/// Returns error if the read-only copy fails or no handler could be launched
+pub async fn open_item(
+    path: PathBuf,
+    mime: mime_guess::Mime,
+    read_only: bool,
+) -> Result<(), String> {
+    tokio::task::spawn_blocking(move || {
+        let target = if read_only {
+            read_only_copy(&path).map_err(|e| format!("Failed to copy item: {e}"))?
+        } else {
+            path
+        };
+
+        launch(&target, &mime)
+    })
+    .await
+    .map_err(|e| {
+        eprintln!("Failed to spawn open_item task: {e}");
+        format!("Task spawn failed: {e}")
+    })?
+}
+
+/// Launches the default handler for `mime`, falling back to `xdg-open`
+fn launch(path: &Path, mime: &mime_guess::Mime) -> Result<(), String> {
+    if let Some(mut command) =
+        default_desktop_entry(mime).and_then(|entry| exec_command(&entry, path))
+    {
+        match command.spawn() {
+            Ok(_) => return Ok(()),
+            Err(e) => eprintln!("Failed to launch default application for {mime}: {e}"),
+        }
+    }
+
+    Command::new("xdg-open")
+        .arg(path)
+        .spawn()
+        .map(|_| ())
+        .map_err(|e| format!("Failed to open {}: {e}", path.display()))
+}
+
+/// Copies the item to `<copies root>/<pid>/<unique>/<name>` and makes it read-only
+fn read_only_copy(path: &Path) -> std::io::Result<PathBuf> {
+    use std::os::unix::fs::DirBuilderExt;
+
+    let name = path
+        .file_name()
+        .ok_or_else(|| std::io::Error::new(std::io::ErrorKind::InvalidInput, "no file name"))?;
+
+    let unique = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_nanos())
+        .unwrap_or_default();
+    let dir = own_copies()?.join(unique.to_string());
+    std::fs::DirBuilder::new()
+        .recursive(true)
+        .mode(0o700)
+        .create(&dir)?;
+
+    let target = dir.join(name);
+    copy_read_only(path, &target)?;
+    Ok(target)
+}
+
+/// Private directory with one subdirectory of read-only copies per applet process
+///
+/// `$XDG_RUNTIME_DIR/cosmic-applet-trash`, or `$TMPDIR/cosmic-applet-trash-<uid>`
+/// without a runtime dir. Other users can neither read the copies nor plant
+/// the directory (or symlinks) in advance: see `private_dir`.
+fn copies_root() -> std::io::Result<PathBuf> {
+    let root = match std::env::var_os("XDG_RUNTIME_DIR")
+        .map(PathBuf::from)
+        .filter(|dir| dir.is_absolute())
+    {
+        Some(runtime) => runtime.join("cosmic-applet-trash"),
+        None => std::env::temp_dir().join(format!("cosmic-applet-trash-{}", current_uid()?)),
+    };
+    private_dir(&root)?;
+    Ok(root)
+}
+
+fn own_copies() -> std::io::Result<PathBuf> {
+    copies_root().map(|root| root.join(std::process::id().to_string()))
+}
+
+/// Creates `dir` with mode `0o700` if missing, then checks it is a real
+/// directory owned by this user and closed to everyone else
+fn private_dir(dir: &Path) -> std::io::Result<()> {
+    use std::os::unix::fs::{DirBuilderExt, MetadataExt};
+
+    match std::fs::DirBuilder::new().mode(0o700).create(dir) {
+        Err(e) if e.kind() != std::io::ErrorKind::AlreadyExists => return Err(e),
+        _ => {}
+    }
+
+    let metadata = std::fs::symlink_metadata(dir)?;
+    if !metadata.is_dir() || metadata.uid() != current_uid()? || metadata.mode() & 0o077 != 0 {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::PermissionDenied,
+            format!("{} is not a private directory", dir.display()),
+        ));
+    }
+    Ok(())
+}
+
+/// User id of this process (owner of `/proc/self`)
+fn current_uid() -> std::io::Result<u32> {
+    use std::os::unix::fs::MetadataExt;
+
+    std::fs::metadata("/proc/self").map(|metadata| metadata.uid())
+}
+
+/// Removes read-only copies left behind by applet processes that are no longer running
+///
+/// Called at startup, so copies survive a crash only until the next launch.
+pub fn clean_stale_copies() {
+    let Ok(entries) = copies_root().and_then(std::fs::read_dir) else {
+        return;
+    };
+
+    for entry in entries.filter_map(Result::ok) {
+        // `<pid>` (older versions used `<pid>-<nanos>`)
+        let pid = entry
+            .file_name()
+            .to_str()
+            .and_then(|name| name.split('-').next())
+            .and_then(|pid| pid.parse::<u32>().ok());
+        let running = pid.is_some_and(|pid| {
+            pid == std::process::id() || Path::new("/proc").join(pid.to_string()).exists()
+        });
+        if !running {
+            remove_copies(&entry.path());
+        }
+    }
+}
+
+/// Removes this process's read-only copies (called when the applet exits)
+pub fn remove_own_copies() {
+    if let Ok(own) = own_copies() {
+        remove_copies(&own);
+    }
+}
+
+/// Deletes a tree of read-only copies (write permission is restored first)
+fn remove_copies(path: &Path) {
+    let result = make_writable(path).and_then(|()| std::fs::remove_dir_all(path));
+    if let Err(e) = result
+        && e.kind() != std::io::ErrorKind::NotFound
+    {
+        eprintln!(
+            "Failed to remove read-only copies in {}: {e}",
+            path.display()
+        );
+    }
+}
+
+/// Makes every directory under `path` writable so its entries can be removed
+fn make_writable(path: &Path) -> std::io::Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+
+    if std::fs::symlink_metadata(path)?.is_dir() {
+        std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o700))?;
+        for entry in std::fs::read_dir(path)? {
+            make_writable(&entry?.path())?;
+        }
+    }
+    Ok(())
+}
+
+/// Recursively copies `from` to `to`, leaving every copied entry readable only by us
+fn copy_read_only(from: &Path, to: &Path) -> std::io::Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let metadata = std::fs::symlink_metadata(from)?;
+    if metadata.is_dir() {
+        std::fs::create_dir_all(to)?;
+        for entry in std::fs::read_dir(from)? {
+            let entry = entry?;
+            copy_read_only(&entry.path(), &to.join(entry.file_name()))?;
+        }
+        std::fs::set_permissions(to, std::fs::Permissions::from_mode(0o500))
+    } else if metadata.is_symlink() {
+        std::os::unix::fs::symlink(std::fs::read_link(from)?, to)
+    } else {
+        std::fs::copy(from, to)?;
+        std::fs::set_permissions(to, std::fs::Permissions::from_mode(0o400))
+    }
+}
+
+/// XDG config/data directories, in lookup order (user first, then system)
+fn xdg_dirs(
+    home_var: &str,
+    home_default: &str,
+    dirs_var: &str,
+    dirs_default: &str,
+) -> Vec<PathBuf> {
+    let home = std::env::var_os(home_var)
+        .map(PathBuf::from)
+        .filter(|path| path.is_absolute())
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(home_default)));
+
+    let system = std::env::var(dirs_var)
+        .ok()
+        .filter(|dirs| !dirs.is_empty())
+        .unwrap_or_else(|| dirs_default.to_string());
+
+    home.into_iter()
+        .chain(system.split(':').map(PathBuf::from))
+        .collect()
+}
+
+/// `mimeapps.list` files in spec order
+///
+/// `$XDG_CONFIG_HOME`, `$XDG_CONFIG_DIRS`, then `$XDG_DATA_HOME/applications` and
+/// `$XDG_DATA_DIRS/applications` (desktop-specific `<desktop>-mimeapps.list` first).
+fn mimeapps_lists() -> Vec<PathBuf> {
+    let desktops: Vec<String> = std::env::var("XDG_CURRENT_DESKTOP")
+        .unwrap_or_default()
+        .split(':')
+        .filter(|desktop| !desktop.is_empty())
+        .map(str::to_lowercase)
+        .collect();
+
+    let config_dirs = xdg_dirs("XDG_CONFIG_HOME", ".config", "XDG_CONFIG_DIRS", "/etc/xdg");
+    let data_dirs = xdg_dirs(
+        "XDG_DATA_HOME",
+        ".local/share",
+        "XDG_DATA_DIRS",
+        "/usr/local/share:/usr/share",
+    );
+
+    config_dirs
+        .into_iter()
+        .chain(data_dirs.into_iter().map(|dir| dir.join("applications")))
+        .flat_map(|dir| {
+            desktops
+                .iter()
+                .map(|desktop| dir.join(format!("{desktop}-mimeapps.list")))
+                .chain([dir.join("mimeapps.list"), dir.join("defaults.list")])
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+/// Finds the `.desktop` file path for a desktop file id ("org.gnome.eog.desktop")
+fn find_desktop_file(desktop_id: &str) -> Option<PathBuf> {
+    let data_dirs = xdg_dirs(
+        "XDG_DATA_HOME",
+        ".local/share",
+        "XDG_DATA_DIRS",
+        "/usr/local/share:/usr/share",
+    );
+
+    data_dirs
+        .into_iter()
+        .map(|dir| dir.join("applications").join(desktop_id))
+        .find(|path| path.is_file())
+}
+
+/// Resolves the default application's `.desktop` file for a MIME type
+///
+/// `[Default Applications]` wins over `[Added Associations]`; the first listed
+/// desktop id that is installed is used.
+#[must_use]
+pub fn default_desktop_entry(mime: &mime_guess::Mime) -> Option<PathBuf> {
+    let lists: Vec<String> = mimeapps_lists()
+        .iter()
+        .filter_map(|path| std::fs::read_to_string(path).ok())
+        .collect();
+
+    for section in ["Default Applications", "Added Associations"] {
+        for list in &lists {
+            let Some(desktop_ids) = section_entry(list, section, mime.essence_str()) else {
+                continue;
+            };
+
+            let installed = desktop_ids
+                .split(';')
+                .map(str::trim)
+                .filter(|id| !id.is_empty())
+                .find_map(find_desktop_file);
+            if installed.is_some() {
+                return installed;
+            }
+        }
+    }
+
+    None
+}
+
+/// Reads `key=value` from an INI-style `[section]` (returns the raw value)
+fn section_entry<'a>(contents: &'a str, section: &str, key: &str) -> Option<&'a str> {
+    let mut in_section = false;
+
+    for line in contents.lines().map(str::trim) {
+        if line.starts_with('[') {
+            in_section = line.trim_start_matches('[').trim_end_matches(']') == section;
+        } else if in_section
+            && let Some((entry_key, value)) = line.split_once('=')
+            && entry_key.trim() == key
+        {
+            return Some(value.trim());
+        }
+    }
+
+    None
+}
+
+/// Builds the command from the desktop entry's `Exec` key
+///
+/// Field codes: `%f`/`%F` → path, `%u`/`%U` → `file://` URI, `%%` → `%`,
+/// deprecated and unsupported codes (`%i`, `%c`, `%k`, ...) are dropped.
+fn exec_command(desktop_file: &Path, path: &Path) -> Option<Command> {
+    let contents = std::fs::read_to_string(desktop_file).ok()?;
+    let exec = section_entry(&contents, "Desktop Entry", "Exec")?;
+    let uri = url::Url::from_file_path(path).ok()?.to_string();
+
+    let mut args = Vec::new();
+    let mut has_target = false;
+    for token in split_exec(exec) {
+        match token.as_str() {
+            "%f" | "%F" => {
+                args.push(path.as_os_str().to_os_string());
+                has_target = true;
+            }
+            "%u" | "%U" => {
+                args.push(uri.clone().into());
+                has_target = true;
+            }
+            token if token.len() == 2 && token.starts_with('%') => {}
+            token => args.push(token.replace("%%", "%").into()),
+        }
+    }
+
+    // Entries without a field code still expect the file as last argument
+    if !has_target {
+        args.push(path.as_os_str().to_os_string());
+    }
+
+    let (program, args) = args.split_first()?;
+    let mut command = Command::new(program);
+    command.args(args);
+    Some(command)
+}
+
+/// Splits an `Exec` value into arguments (handles double quotes and backslash escapes)
+fn split_exec(exec: &str) -> Vec<String> {
+    let mut args = Vec::new();
+    let mut current = String::new();
+    let mut in_quotes = false;
+    let mut chars = exec.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => in_quotes = !in_quotes,
+            '\\' if in_quotes => {
+                if let Some(escaped) = chars.next() {
+                    current.push(escaped);
+                }
+            }
+            c if c.is_whitespace() && !in_quotes => {
+                if !current.is_empty() {
+                    args.push(std::mem::take(&mut current));
+                }
+            }
+            c => current.push(c),
+        }
+    }
+    if !current.is_empty() {
+        args.push(current);
+    }
+
+    args
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn read_only_copies_can_be_removed() {
+        let dir = tempfile::tempdir().unwrap();
+        let source = dir.path().join("folder");
+        std::fs::create_dir_all(source.join("nested")).unwrap();
+        std::fs::write(source.join("nested/file.txt"), b"data").unwrap();
+
+        let copy = dir.path().join("copies");
+        copy_read_only(&source, &copy).unwrap();
+        assert!(
+            std::fs::metadata(copy.join("nested/file.txt"))
+                .unwrap()
+                .permissions()
+                .readonly()
+        );
+
+        remove_copies(&copy);
+        assert!(!copy.exists());
+        assert!(source.join("nested/file.txt").exists());
+    }
+
+    #[test]
+    fn read_only_copies_are_private() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let source = dir.path().join("folder");
+        std::fs::create_dir(&source).unwrap();
+        std::fs::write(source.join("file.txt"), b"data").unwrap();
+
+        let copy = dir.path().join("copies");
+        copy_read_only(&source, &copy).unwrap();
+        let mode = |path: &Path| std::fs::metadata(path).unwrap().permissions().mode() & 0o777;
+        assert_eq!(mode(&copy), 0o500);
+        assert_eq!(mode(&copy.join("file.txt")), 0o400);
+
+        remove_copies(&copy);
+    }
+
+    #[test]
+    fn private_dir_refuses_shared_or_planted_dirs() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let created = dir.path().join("created");
+        private_dir(&created).unwrap();
+        assert_eq!(
+            std::fs::metadata(&created).unwrap().permissions().mode() & 0o777,
+            0o700
+        );
+        // Existing private dir is reused
+        private_dir(&created).unwrap();
+
+        let shared = dir.path().join("shared");
+        std::fs::create_dir(&shared).unwrap();
+        std::fs::set_permissions(&shared, std::fs::Permissions::from_mode(0o755)).unwrap();
+        assert!(private_dir(&shared).is_err());
+
+        let planted = dir.path().join("planted");
+        std::os::unix::fs::symlink(&created, &planted).unwrap();
+        assert!(private_dir(&planted).is_err());
+    }
+}
@@ -0,0 +1,151 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Crate-level error type for trash operations
+//!
+//! `trash_operations` maps `trash::Error` (and I/O errors) into these variants
+//! so callers can pattern-match and pick the right UI response instead of
+//! stringifying errors immediately.
+
+use crate::fl;
+use std::path::{Path, PathBuf};
+
+/// Error returned by trash operations
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TrashError {
+    /// Insufficient permissions on the item, its bin or its original location
+    PermissionDenied { path: Option<PathBuf> },
+    /// The item (or its original parent) no longer exists
+    NotFound { path: Option<PathBuf> },
+    /// Something already exists at the restore destination
+    Conflict { path: PathBuf },
+    /// The operation would move data across filesystems
+    CrossDevice { path: Option<PathBuf> },
+    /// Some items failed while others succeeded
+    PartialFailure { failed: Vec<ItemFailure> },
+    /// The background task panicked or was cancelled
+    TaskJoin(String),
+    /// Anything else (description from the underlying error)
+    Other(String),
+}
+
+/// A single item that failed within a bulk operation
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ItemFailure {
+    pub item: trash::TrashItem,
+    pub error: TrashError,
+}
+
+impl TrashError {
+    /// Localized, user-facing description
+    #[must_use]
+    pub fn message(&self) -> String {
+        match self {
+            Self::PermissionDenied { path: Some(path) } => {
+                fl!(
+                    "error-permission-denied-path",
+                    path = path.display().to_string()
+                )
+            }
+            Self::PermissionDenied { path: None } => fl!("error-permission-denied"),
+            Self::NotFound { path: Some(path) } => {
+                fl!("error-not-found-path", path = path.display().to_string())
+            }
+            Self::NotFound { path: None } => fl!("error-not-found"),
+            Self::Conflict { path } => {
+                fl!("error-conflict", path = path.display().to_string())
+            }
+            Self::CrossDevice { path: Some(path) } => {
+                fl!("error-cross-device-path", path = path.display().to_string())
+            }
+            Self::CrossDevice { path: None } => fl!("error-cross-device"),
+            Self::PartialFailure { failed } => {
+                fl!("error-partial-failure", count = failed.len())
+            }
+            Self::TaskJoin(description) | Self::Other(description) => description.clone(),
+        }
+    }
+
+    /// Whether retrying the same operation may succeed
+    ///
+    /// Conflicts and missing items need user action (or a refresh) first.
+    #[must_use]
+    pub fn is_retryable(&self) -> bool {
+        !matches!(self, Self::Conflict { .. } | Self::NotFound { .. })
+    }
+
+    /// Maps an I/O error that happened on `path`
+    #[must_use]
+    pub fn io(path: &Path, e: &std::io::Error) -> Self {
+        Self::from_io(
+            e.kind(),
+            Some(path.to_path_buf()),
+            format!("{}: {e}", path.display()),
+        )
+    }
+
+    /// Maps an I/O error kind on `path`
+    fn from_io(kind: std::io::ErrorKind, path: Option<PathBuf>, description: String) -> Self {
+        match kind {
+            std::io::ErrorKind::PermissionDenied | std::io::ErrorKind::ReadOnlyFilesystem => {
+                Self::PermissionDenied { path }
+            }
+            std::io::ErrorKind::NotFound => Self::NotFound { path },
+            std::io::ErrorKind::AlreadyExists | std::io::ErrorKind::DirectoryNotEmpty => match path
+            {
+                Some(path) => Self::Conflict { path },
+                None => Self::Other(description),
+            },
+            std::io::ErrorKind::CrossesDevices => Self::CrossDevice { path },
+            _ => Self::Other(description),
+        }
+    }
+}
+
+impl std::fmt::Display for TrashError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message())
+    }
+}
+
+impl std::error::Error for TrashError {}
+
+impl From<trash::Error> for TrashError {
+    fn from(e: trash::Error) -> Self {
+        let description = e.to_string();
+
+        match e {
+            trash::Error::FileSystem { path, source } => {
+                Self::from_io(source.kind(), Some(path), description)
+            }
+            trash::Error::Os { code, .. } => Self::from_io(
+                std::io::Error::from_raw_os_error(code).kind(),
+                None,
+                description,
+            ),
+            trash::Error::RestoreCollision { path, .. }
+            | trash::Error::RestoreTwins { path, .. } => Self::Conflict { path },
+            trash::Error::CouldNotAccess { target } => {
+                let path = PathBuf::from(target);
+                if path.exists() {
+                    Self::PermissionDenied { path: Some(path) }
+                } else {
+                    Self::NotFound { path: Some(path) }
+                }
+            }
+            trash::Error::Unknown { description } => Self::Other(description),
+            _ => Self::Other(description),
+        }
+    }
+}
+
+impl From<tokio::task::JoinError> for TrashError {
+    fn from(e: tokio::task::JoinError) -> Self {
+        Self::TaskJoin(format!("Task spawn failed: {e}"))
+    }
+}
+
+impl From<String> for TrashError {
+    fn from(description: String) -> Self {
+        Self::Other(description)
+    }
+}
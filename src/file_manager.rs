@@ -1,21 +1,498 @@
 // SPDX-License-Identifier: GPL-3.0-only
 
-//! Native integration with cosmic-files
+//! Native integration with the user's file manager
 //!
 //! Uses `std::process::Command` following official COSMIC pattern
 //! Ref: cosmic-files/cosmic-files-applet/src/file_manager.rs
+//!
+//! The file manager is configurable (built-in profiles or a custom command
+//! template). If it cannot be spawned, falls back to the
+//! `org.freedesktop.FileManager1` D-Bus service ([`FileManager1Client`])
+//! and finally to `xdg-open`.
 
+use crate::fl;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
 use std::process::Command;
+use std::time::Duration;
+
+/// Trash location as understood by GIO/KIO based file managers
+const TRASH_URI: &str = "trash:///";
+
+/// Built-in file manager profiles
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FileManager {
+    #[default]
+    CosmicFiles,
+    Nautilus,
+    Dolphin,
+    Thunar,
+    /// Uses `Config::custom_file_manager` as command template
+    Custom,
+}
+
+impl FileManager {
+    pub const ALL: [Self; 5] = [
+        Self::CosmicFiles,
+        Self::Nautilus,
+        Self::Dolphin,
+        Self::Thunar,
+        Self::Custom,
+    ];
+
+    /// Display name (settings)
+    #[must_use]
+    pub fn name(self) -> String {
+        match self {
+            Self::CosmicFiles => fl!("file-manager-cosmic-files"),
+            Self::Nautilus => "Nautilus".to_string(),
+            Self::Dolphin => "Dolphin".to_string(),
+            Self::Thunar => "Thunar".to_string(),
+            Self::Custom => fl!("file-manager-custom"),
+        }
+    }
+
+    /// Command that opens the trash view
+    ///
+    /// - cosmic-files: `--trash` (ref: cosmic-files/src/lib.rs lines 123-124)
+    /// - nautilus/thunar: `trash:///` (GIO), dolphin: `trash:/` (KIO)
+    fn trash_command(self, custom_template: &str) -> Option<Command> {
+        match self {
+            Self::CosmicFiles => Some(command("cosmic-files", ["--trash"])),
+            Self::Nautilus => Some(command("nautilus", [TRASH_URI])),
+            Self::Dolphin => Some(command("dolphin", ["trash:/"])),
+            Self::Thunar => Some(command("thunar", [TRASH_URI])),
+            Self::Custom => custom_command(
+                custom_template,
+                TRASH_URI,
+                &crate::trash_status::TrashStatus::trash_dir(),
+            ),
+        }
+    }
+
+    /// Command that shows `target` (a file is highlighted in its parent folder when supported)
+    fn show_command(self, custom_template: &str, target: &Path) -> Option<Command> {
+        let is_file = !target.is_dir();
+        let mut command = match self {
+            // cosmic-files opens the parent and highlights when given a file path
+            Self::CosmicFiles => Command::new("cosmic-files"),
+            Self::Nautilus | Self::Dolphin => {
+                let mut command = Command::new(if self == Self::Nautilus {
+                    "nautilus"
+                } else {
+                    "dolphin"
+                });
+                if is_file {
+                    command.arg("--select");
+                }
+                command
+            }
+            // Thunar has no selection flag: open the containing folder
+            Self::Thunar => {
+                let folder = if is_file {
+                    target.parent().unwrap_or(target)
+                } else {
+                    target
+                };
+                let mut command = Command::new("thunar");
+                command.arg(folder);
+                return Some(command);
+            }
+            Self::Custom => {
+                let uri = url::Url::from_file_path(target).ok()?;
+                return custom_command(custom_template, uri.as_str(), target);
+            }
+        };
+        command.arg(target);
+        Some(command)
+    }
+}
 
-/// Opens the trash folder in cosmic-files
+/// Builds a command from program + fixed arguments
+fn command<const N: usize>(program: &str, args: [&str; N]) -> Command {
+    let mut command = Command::new(program);
+    command.args(args);
+    command
+}
+
+/// Builds a command from a custom template ("pcmanfm {uri}", "nemo {path}")
 ///
-/// Uses the `--trash` argument which is natively supported by cosmic-files
-/// (ref: cosmic-files/src/lib.rs lines 123-124)
-pub fn open_trash_folder() {
-    match Command::new("cosmic-files").arg("--trash").spawn() {
-        Ok(_) => {}
+/// Placeholders: `{uri}` (URI of the location) and `{path}` (local path).
+/// Without placeholders, the URI is appended as last argument.
+fn custom_command(template: &str, uri: &str, path: &Path) -> Option<Command> {
+    let mut tokens = template.split_whitespace();
+    let program = tokens.next()?;
+    let path = path.to_string_lossy();
+
+    let mut args: Vec<String> = tokens
+        .map(|token| token.replace("{uri}", uri).replace("{path}", &path))
+        .collect();
+    if !template.contains("{uri}") && !template.contains("{path}") {
+        args.push(uri.to_string());
+    }
+
+    let mut command = Command::new(program);
+    command.args(args);
+    Some(command)
+}
+
+/// Spawns the command, returning whether the file manager was launched
+fn spawn(command: Option<Command>) -> bool {
+    let Some(mut command) = command else {
+        eprintln!("Custom file manager command is empty");
+        return false;
+    };
+
+    match command.spawn() {
+        Ok(_) => true,
         Err(e) => {
-            eprintln!("Failed to open cosmic-files: {e}");
+            eprintln!(
+                "Failed to launch {}: {e}",
+                command.get_program().to_string_lossy()
+            );
+            false
         }
     }
 }
+
+/// `org.freedesktop.FileManager1` well-known name (also interface name)
+const FILE_MANAGER1_NAME: &str = "org.freedesktop.FileManager1";
+
+/// Default timeout for FileManager1 calls (service activation included)
+const FILE_MANAGER1_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// `org.freedesktop.FileManager1` interface
+/// Ref: https://www.freedesktop.org/wiki/Specifications/file-manager-interface/
+#[zbus::proxy(
+    interface = "org.freedesktop.FileManager1",
+    default_service = "org.freedesktop.FileManager1",
+    default_path = "/org/freedesktop/FileManager1"
+)]
+trait FileManager1 {
+    fn show_folders(&self, uris: &[&str], startup_id: &str) -> zbus::Result<()>;
+    fn show_items(&self, uris: &[&str], startup_id: &str) -> zbus::Result<()>;
+}
+
+/// FileManager1 client errors
+#[derive(Debug)]
+pub enum FileManager1Error {
+    /// No service owns (or can be activated for) the FileManager1 name
+    NoService,
+    /// The call did not complete within the client timeout
+    Timeout(Duration),
+    /// D-Bus connection or method call failure
+    DBus(zbus::Error),
+}
+
+impl std::fmt::Display for FileManager1Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NoService => write!(f, "no service provides {FILE_MANAGER1_NAME}"),
+            Self::Timeout(timeout) => write!(f, "{FILE_MANAGER1_NAME} timed out after {timeout:?}"),
+            Self::DBus(e) => write!(f, "{FILE_MANAGER1_NAME} call failed: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for FileManager1Error {}
+
+impl From<zbus::Error> for FileManager1Error {
+    fn from(e: zbus::Error) -> Self {
+        Self::DBus(e)
+    }
+}
+
+impl From<zbus::fdo::Error> for FileManager1Error {
+    fn from(e: zbus::fdo::Error) -> Self {
+        Self::DBus(zbus::Error::from(e))
+    }
+}
+
+/// Async client for `org.freedesktop.FileManager1`
+///
+/// Lets any compliant file manager show folders or highlight specific files.
+/// Built on a caller-provided connection so it can run against a stub bus.
+pub struct FileManager1Client {
+    connection: zbus::Connection,
+    timeout: Duration,
+}
+
+impl FileManager1Client {
+    /// Connects to the session bus
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the session bus is unreachable (within the timeout)
+    pub async fn session() -> Result<Self, FileManager1Error> {
+        let connection = tokio::time::timeout(FILE_MANAGER1_TIMEOUT, zbus::Connection::session())
+            .await
+            .map_err(|_| FileManager1Error::Timeout(FILE_MANAGER1_TIMEOUT))??;
+        Ok(Self::with_connection(connection))
+    }
+
+    /// Uses an existing connection (e.g. a private or stub bus)
+    #[must_use]
+    pub fn with_connection(connection: zbus::Connection) -> Self {
+        Self {
+            connection,
+            timeout: FILE_MANAGER1_TIMEOUT,
+        }
+    }
+
+    /// Overrides the per-call timeout
+    #[must_use]
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Opens the folders (URIs) in the file manager
+    ///
+    /// # Errors
+    ///
+    /// See [`FileManager1Error`]
+    pub async fn show_folders(&self, uris: &[&str]) -> Result<(), FileManager1Error> {
+        self.call(|proxy| async move { proxy.show_folders(uris, "").await })
+            .await
+    }
+
+    /// Opens the parent folders of the items (URIs) and highlights them
+    ///
+    /// # Errors
+    ///
+    /// See [`FileManager1Error`]
+    pub async fn show_items(&self, uris: &[&str]) -> Result<(), FileManager1Error> {
+        self.call(|proxy| async move { proxy.show_items(uris, "").await })
+            .await
+    }
+
+    /// Checks the name is owned or activatable, then runs the call with the timeout
+    async fn call<'a, F, Fut>(&'a self, method: F) -> Result<(), FileManager1Error>
+    where
+        F: FnOnce(FileManager1Proxy<'a>) -> Fut,
+        Fut: std::future::Future<Output = zbus::Result<()>>,
+    {
+        let request = async {
+            let dbus = zbus::fdo::DBusProxy::new(&self.connection).await?;
+            let name =
+                zbus::names::BusName::try_from(FILE_MANAGER1_NAME).map_err(zbus::Error::from)?;
+            let has_owner = dbus.name_has_owner(name).await?;
+            let activatable = has_owner
+                || dbus
+                    .list_activatable_names()
+                    .await?
+                    .iter()
+                    .any(|activatable| activatable.as_str() == FILE_MANAGER1_NAME);
+            if !activatable {
+                return Err(FileManager1Error::NoService);
+            }
+
+            let proxy = FileManager1Proxy::new(&self.connection).await?;
+            method(proxy).await.map_err(FileManager1Error::from)
+        };
+
+        tokio::time::timeout(self.timeout, request)
+            .await
+            .map_err(|_| FileManager1Error::Timeout(self.timeout))?
+    }
+}
+
+/// Shows folders or highlights items via FileManager1 on the session bus
+async fn dbus_file_manager(uri: &str, highlight: bool) -> Result<(), FileManager1Error> {
+    let client = FileManager1Client::session().await?;
+    if highlight {
+        client.show_items(&[uri]).await
+    } else {
+        client.show_folders(&[uri]).await
+    }
+}
+
+/// Final fallback: `xdg-open <uri>`
+fn xdg_open(uri: &str) -> Result<(), String> {
+    Command::new("xdg-open")
+        .arg(uri)
+        .spawn()
+        .map(|_| ())
+        .map_err(|e| format!("No file manager could open {uri}: {e}"))
+}
+
+/// Opens the trash folder in the configured file manager
+///
+/// # Errors
+///
+/// Returns error if the file manager, the FileManager1 D-Bus service and
+/// `xdg-open` all failed
+pub async fn open_trash_folder(
+    file_manager: FileManager,
+    custom_template: String,
+) -> Result<(), String> {
+    if spawn(file_manager.trash_command(&custom_template)) {
+        return Ok(());
+    }
+
+    match dbus_file_manager(TRASH_URI, false).await {
+        Ok(()) => Ok(()),
+        Err(e) => {
+            eprintln!("FileManager1 fallback failed: {e}");
+            xdg_open(TRASH_URI)
+        }
+    }
+}
+
+/// Opens the configured file manager at an item's original location
+///
+/// If something exists at `original_path` (restored item, or a file that was
+/// recreated there) it is highlighted in its parent folder. Otherwise opens
+/// `original_parent`, or its closest existing ancestor when the folder itself
+/// was removed.
+///
+/// # Errors
+///
+/// Returns error if no location exists or every launch method failed
+pub async fn reveal_original_location(
+    original_path: PathBuf,
+    file_manager: FileManager,
+    custom_template: String,
+) -> Result<(), String> {
+    let target = if original_path.exists() {
+        original_path
+    } else {
+        original_path
+            .ancestors()
+            .skip(1)
+            .find(|path| path.is_dir())
+            .map(Path::to_path_buf)
+            .ok_or_else(|| format!("No existing location for {}", original_path.display()))?
+    };
+
+    if spawn(file_manager.show_command(&custom_template, &target)) {
+        return Ok(());
+    }
+
+    let uri = url::Url::from_file_path(&target)
+        .map_err(|()| format!("Invalid path: {}", target.display()))?;
+    match dbus_file_manager(uri.as_str(), !target.is_dir()).await {
+        Ok(()) => Ok(()),
+        Err(e) => {
+            eprintln!("FileManager1 fallback failed: {e}");
+            let folder = if target.is_dir() {
+                uri
+            } else {
+                target
+                    .parent()
+                    .and_then(|parent| url::Url::from_file_path(parent).ok())
+                    .unwrap_or(uri)
+            };
+            xdg_open(folder.as_str())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader};
+    use std::process::{Child, Stdio};
+    use std::sync::{Arc, Mutex};
+
+    /// Private `dbus-daemon`, killed on drop
+    struct PrivateBus {
+        daemon: Child,
+        address: String,
+    }
+
+    impl PrivateBus {
+        fn start() -> Self {
+            let mut daemon = Command::new("dbus-daemon")
+                .args(["--session", "--nofork", "--print-address"])
+                .stdout(Stdio::piped())
+                .spawn()
+                .expect("dbus-daemon must be installed to run the ignored D-Bus tests");
+            let mut address = String::new();
+            BufReader::new(daemon.stdout.take().unwrap())
+                .read_line(&mut address)
+                .unwrap();
+            Self {
+                daemon,
+                address: address.trim().to_string(),
+            }
+        }
+
+        fn connect(&self) -> zbus::connection::Builder<'static> {
+            zbus::connection::Builder::address(self.address.as_str()).unwrap()
+        }
+    }
+
+    impl Drop for PrivateBus {
+        fn drop(&mut self) {
+            let _ = self.daemon.kill();
+            let _ = self.daemon.wait();
+        }
+    }
+
+    /// Records the FileManager1 calls it receives
+    #[derive(Clone, Default)]
+    struct StubFileManager {
+        calls: Arc<Mutex<Vec<(&'static str, Vec<String>)>>>,
+    }
+
+    #[zbus::interface(name = "org.freedesktop.FileManager1")]
+    impl StubFileManager {
+        fn show_folders(&self, uris: Vec<String>, _startup_id: &str) {
+            self.calls.lock().unwrap().push(("ShowFolders", uris));
+        }
+
+        fn show_items(&self, uris: Vec<String>, _startup_id: &str) {
+            self.calls.lock().unwrap().push(("ShowItems", uris));
+        }
+    }
+
+    #[tokio::test]
+    #[ignore = "needs dbus-daemon (run with --ignored)"]
+    async fn client_calls_file_manager1_on_the_bus() {
+        let bus = PrivateBus::start();
+        let stub = StubFileManager::default();
+        let _service = bus
+            .connect()
+            .name(FILE_MANAGER1_NAME)
+            .unwrap()
+            .serve_at("/org/freedesktop/FileManager1", stub.clone())
+            .unwrap()
+            .build()
+            .await
+            .unwrap();
+        let client = FileManager1Client::with_connection(bus.connect().build().await.unwrap());
+
+        client.show_folders(&[TRASH_URI]).await.unwrap();
+        client
+            .show_items(&["file:///tmp/a.txt", "file:///tmp/b.txt"])
+            .await
+            .unwrap();
+
+        assert_eq!(
+            *stub.calls.lock().unwrap(),
+            vec![
+                ("ShowFolders", vec![TRASH_URI.to_string()]),
+                (
+                    "ShowItems",
+                    vec![
+                        "file:///tmp/a.txt".to_string(),
+                        "file:///tmp/b.txt".to_string()
+                    ]
+                ),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    #[ignore = "needs dbus-daemon (run with --ignored)"]
+    async fn client_reports_missing_service() {
+        let bus = PrivateBus::start();
+        let client = FileManager1Client::with_connection(bus.connect().build().await.unwrap());
+
+        assert!(matches!(
+            client.show_folders(&[TRASH_URI]).await,
+            Err(FileManager1Error::NoService)
+        ));
+    }
+}
@@ -49,3 +49,89 @@ macro_rules! fl {
         i18n_embed_fl::fl!($crate::i18n::LANGUAGE_LOADER, $message_id, $($args), *)
     }};
 }
+
+/// Languages writing `1,5` (decimal comma) instead of `1.5`
+const DECIMAL_COMMA: &[&str] = &[
+    "bg", "ca", "cs", "da", "de", "el", "es", "et", "eu", "fi", "fr", "gl", "hr", "hu", "id", "it",
+    "lt", "lv", "nb", "nl", "nn", "pl", "pt", "ro", "ru", "sk", "sl", "sr", "sv", "tr", "uk", "vi",
+];
+
+/// Decimal-comma languages grouping thousands with a (narrow) space instead of `.`
+const SPACE_GROUPING: &[&str] = &[
+    "bg", "cs", "et", "fi", "fr", "hu", "lt", "lv", "nb", "nn", "pl", "ru", "sk", "sv", "uk",
+];
+
+/// Decimal and thousands separators of the selected language
+fn separators() -> (char, char) {
+    let language = LANGUAGE_LOADER.current_language();
+    let language = language.language.as_str();
+
+    if !DECIMAL_COMMA.contains(&language) {
+        ('.', ',')
+    } else if SPACE_GROUPING.contains(&language) {
+        (',', '\u{202f}')
+    } else {
+        (',', '.')
+    }
+}
+
+/// Formats `value` with `decimals` fraction digits and locale separators
+#[must_use]
+pub fn format_decimal(value: f64, decimals: usize) -> String {
+    let (decimal, group) = separators();
+    let formatted = format!("{value:.decimals$}");
+    let (integer, fraction) = formatted
+        .split_once('.')
+        .map_or((formatted.as_str(), None), |(integer, fraction)| {
+            (integer, Some(fraction))
+        });
+    let (sign, digits) = integer
+        .strip_prefix('-')
+        .map_or(("", integer), |digits| ("-", digits));
+
+    let mut result = String::from(sign);
+    for (index, digit) in digits.chars().enumerate() {
+        if index > 0 && (digits.len() - index) % 3 == 0 {
+            result.push(group);
+        }
+        result.push(digit);
+    }
+    if let Some(fraction) = fraction {
+        result.push(decimal);
+        result.push_str(fraction);
+    }
+
+    result
+}
+
+/// Formats a count with locale thousands separators
+#[must_use]
+#[allow(clippy::cast_precision_loss)]
+pub fn format_count(count: u64) -> String {
+    format_decimal(count as f64, 0)
+}
+
+/// Rough duration in the largest fitting unit ("5 days", "3 hours", "12 minutes")
+#[must_use]
+pub fn format_duration(seconds: i64) -> String {
+    const MINUTE: i64 = 60;
+    const HOUR: i64 = 60 * MINUTE;
+    const DAY: i64 = 24 * HOUR;
+
+    // `fl!` arguments must have a known type, hence the bindings
+    match seconds.max(0) {
+        seconds if seconds >= DAY => {
+            let days = seconds / DAY;
+            fl!("duration-days", count = days)
+        }
+        seconds if seconds >= HOUR => {
+            let hours = seconds / HOUR;
+            fl!("duration-hours", count = hours)
+        }
+        seconds if seconds >= MINUTE => {
+            let minutes = seconds / MINUTE;
+            fl!("duration-minutes", count = minutes)
+        }
+        _ => fl!("duration-less-than-minute"),
+    }
+}
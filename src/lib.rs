@@ -8,13 +8,29 @@
 //! - Drag & Drop for app uninstall (Flatpak/PackageKit)
 
 pub mod app;
+pub mod cleanup_rules;
+pub mod cli;
 pub mod config;
+pub mod config_migration;
+pub mod dbus_service;
+pub mod default_app;
+pub mod error;
 pub mod file_manager;
 pub mod i18n;
 pub mod mime_icon;
+pub mod notifications;
+pub mod operation_error;
+pub mod secure_erase;
+pub mod size_format;
+pub mod trash_export;
 pub mod trash_item_metadata;
 pub mod trash_operations;
+pub mod trash_preview;
 pub mod trash_status;
+pub mod ui_error_banner;
 pub mod ui_items;
 pub mod ui_panel_button;
 pub mod ui_popup;
+pub mod ui_preview;
+pub mod ui_rules;
+pub mod ui_settings;
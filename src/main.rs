@@ -1,22 +1,51 @@
 // SPDX-License-Identifier: GPL-3.0-only
 
 mod app;
+mod cleanup_rules;
+mod cli;
 mod config;
+mod config_migration;
+mod dbus_service;
+mod default_app;
+mod error;
 mod file_manager;
 mod i18n;
 mod mime_icon;
+mod notifications;
+mod operation_error;
+mod secure_erase;
+mod size_format;
+mod trash_export;
 mod trash_item_metadata;
 mod trash_operations;
+mod trash_preview;
 mod trash_status;
+mod ui_error_banner;
 mod ui_items;
 mod ui_panel_button;
 mod ui_popup;
+mod ui_preview;
+mod ui_rules;
+mod ui_settings;
 
 fn main() -> cosmic::iced::Result {
     // Initialize i18n
     let requested_languages = i18n_embed::DesktopLanguageRequester::requested_languages();
     i18n::init(&requested_languages);
 
+    // Carry settings over from older config schema versions
+    config_migration::run();
+
+    // Headless CLI mode (`list`, `put`, `restore`, ...): no compositor needed
+    if let Some(exit_code) = cli::run_if_requested() {
+        std::process::exit(exit_code);
+    }
+
+    // Read-only copies of opened items (see `default_app::open_item`)
+    default_app::clean_stale_copies();
+
     // Run applet
-    cosmic::applet::run::<app::AppModel>(())
+    let result = cosmic::applet::run::<app::AppModel>(());
+    default_app::remove_own_copies();
+    result
 }
@@ -0,0 +1,196 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Native integration: freedesktop desktop notifications
+//!
+//! Sends trash events through `org.freedesktop.Notifications` (any compliant
+//! daemon, including cosmic-notifications) and listens for `ActionInvoked`
+//! so notification buttons can drive the applet.
+//! Ref: https://specifications.freedesktop.org/notification-spec/latest/
+
+use crate::fl;
+use crate::size_format::{self, SizeUnits};
+use futures_util::{Stream, StreamExt};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use zbus::zvariant::Value;
+
+/// Notification icon (the app name is the localized "Trash")
+const APP_ICON: &str = "user-trash-full-symbolic";
+const DESKTOP_ENTRY: &str = "com.github.thiagocys.CosmicAppletTrash";
+
+/// Action keys are prefixed so signals for other applications' notifications are ignored
+/// (other instances of the applet are told apart by notification id)
+const ACTION_OPEN_TRASH: &str = "cosmic-applet-trash.open";
+const ACTION_EMPTY_TRASH: &str = "cosmic-applet-trash.empty";
+const ACTION_RETRY_PREFIX: &str = "cosmic-applet-trash.retry:";
+const ACTION_UNDO_RESTORE_PREFIX: &str = "cosmic-applet-trash.undo-restore:";
+
+#[zbus::proxy(
+    interface = "org.freedesktop.Notifications",
+    default_service = "org.freedesktop.Notifications",
+    default_path = "/org/freedesktop/Notifications"
+)]
+trait Notifications {
+    #[allow(clippy::too_many_arguments)]
+    fn notify(
+        &self,
+        app_name: &str,
+        replaces_id: u32,
+        app_icon: &str,
+        summary: &str,
+        body: &str,
+        actions: &[&str],
+        hints: HashMap<&str, Value<'_>>,
+        expire_timeout: i32,
+    ) -> zbus::Result<u32>;
+
+    #[zbus(signal)]
+    fn action_invoked(&self, id: u32, action_key: String) -> zbus::Result<()>;
+}
+
+/// Trash events that can raise a notification (each toggled in `Config`)
+#[derive(Debug, Clone)]
+pub enum NotificationEvent {
+    /// "Empty trash" completed
+    TrashEmptied { count: usize },
+    /// Cleanup policies purged items automatically
+    AutoPurged { count: usize },
+    /// An item was restored (offers "Undo")
+    Restored { path: PathBuf, name: String },
+    /// Restoring an item failed (offers "Retry")
+    RestoreFailed {
+        id: String,
+        name: String,
+        error: String,
+    },
+    /// Trash size crossed the configured threshold
+    SizeThreshold { total_bytes: u64, units: SizeUnits },
+}
+
+/// Button pressed on one of the applet's notifications
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NotificationAction {
+    OpenTrash,
+    EmptyTrash,
+    /// Retry restoring the trash item with this id
+    RetryRestore(String),
+    /// Move the item restored to this path back to the trash
+    UndoRestore(PathBuf),
+}
+
+impl NotificationAction {
+    fn parse(action_key: &str) -> Option<Self> {
+        match action_key {
+            ACTION_OPEN_TRASH => Some(Self::OpenTrash),
+            ACTION_EMPTY_TRASH => Some(Self::EmptyTrash),
+            key => key
+                .strip_prefix(ACTION_RETRY_PREFIX)
+                .map(|id| Self::RetryRestore(id.to_string()))
+                .or_else(|| {
+                    key.strip_prefix(ACTION_UNDO_RESTORE_PREFIX)
+                        .map(|path| Self::UndoRestore(PathBuf::from(path)))
+                }),
+        }
+    }
+}
+
+impl NotificationEvent {
+    /// (summary, body, actions as [key, label, key, label, ...])
+    fn content(&self) -> (String, String, Vec<String>) {
+        match self {
+            Self::TrashEmptied { count } => {
+                let count = *count;
+                (
+                    fl!("notify-emptied"),
+                    fl!("notify-emptied-body", count = count),
+                    Vec::new(),
+                )
+            }
+            Self::AutoPurged { count } => {
+                let count = *count;
+                (
+                    fl!("notify-auto-purged"),
+                    fl!("notify-auto-purged-body", count = count),
+                    vec![ACTION_OPEN_TRASH.to_string(), fl!("open-trash-action")],
+                )
+            }
+            Self::Restored { path, name } => (
+                fl!("notify-restored", name = name.clone()),
+                path.display().to_string(),
+                vec![
+                    format!("{ACTION_UNDO_RESTORE_PREFIX}{}", path.display()),
+                    fl!("undo"),
+                ],
+            ),
+            Self::RestoreFailed { id, name, error } => (
+                fl!("notify-restore-failed", name = name.clone()),
+                error.clone(),
+                vec![
+                    format!("{ACTION_RETRY_PREFIX}{id}"),
+                    fl!("retry"),
+                    ACTION_OPEN_TRASH.to_string(),
+                    fl!("open-trash-action"),
+                ],
+            ),
+            Self::SizeThreshold { total_bytes, units } => (
+                fl!("notify-size-threshold"),
+                fl!(
+                    "notify-size-threshold-body",
+                    size = size_format::format(*total_bytes, *units)
+                ),
+                vec![
+                    ACTION_EMPTY_TRASH.to_string(),
+                    fl!("empty-trash"),
+                    ACTION_OPEN_TRASH.to_string(),
+                    fl!("open-trash-action"),
+                ],
+            ),
+        }
+    }
+}
+
+/// Sends a notification for the event, returning its id
+///
+/// # Errors
+///
+/// Returns error if the session bus or notification daemon is unavailable
+pub async fn send(event: NotificationEvent) -> zbus::Result<u32> {
+    let connection = zbus::Connection::session().await?;
+    let proxy = NotificationsProxy::new(&connection).await?;
+
+    let (summary, body, actions) = event.content();
+    let actions: Vec<&str> = actions.iter().map(String::as_str).collect();
+    let hints = HashMap::from([("desktop-entry", Value::from(DESKTOP_ENTRY))]);
+
+    proxy
+        .notify(
+            &fl!("trash"),
+            0,
+            APP_ICON,
+            &summary,
+            &body,
+            &actions,
+            hints,
+            -1,
+        )
+        .await
+}
+
+/// Stream of actions invoked on the applet's notifications, with the notification id
+///
+/// Every applet instance receives every signal: callers only act on ids that
+/// `send` returned to them.
+///
+/// # Errors
+///
+/// Returns error if the session bus is unavailable
+pub async fn actions() -> zbus::Result<impl Stream<Item = (u32, NotificationAction)>> {
+    let connection = zbus::Connection::session().await?;
+    let proxy = NotificationsProxy::new(&connection).await?;
+    let signals = proxy.receive_action_invoked().await?;
+
+    Ok(signals.filter_map(|signal| async move {
+        let args = signal.args().ok()?;
+        Some((args.id, NotificationAction::parse(&args.action_key)?))
+    }))
+}
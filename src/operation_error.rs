@@ -0,0 +1,125 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Structured errors for user-facing operations
+//!
+//! Keeps which operation failed and on which items, so the popup can show a
+//! meaningful banner and offer a retry instead of a bare error string.
+
+use crate::error::TrashError;
+use crate::fl;
+use crate::trash_item_metadata::EnrichedTrashItem;
+
+/// Maximum number of errors kept for the banner (oldest dropped first)
+pub const MAX_RECENT_ERRORS: usize = 5;
+
+/// Operation that failed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operation {
+    Empty,
+    Restore,
+    Delete,
+    Open,
+    FileManager,
+    Export,
+    Cleanup,
+}
+
+impl Operation {
+    /// Whether the operation can be retried from the banner
+    #[must_use]
+    pub fn is_retryable(self) -> bool {
+        matches!(
+            self,
+            Self::Empty | Self::Restore | Self::Delete | Self::Open
+        )
+    }
+}
+
+/// A failed operation, with the affected items
+#[derive(Debug, Clone)]
+pub struct OperationError {
+    pub operation: Operation,
+    /// Affected items (empty for whole-trash operations)
+    pub items: Vec<EnrichedTrashItem>,
+    /// Underlying error
+    pub error: TrashError,
+}
+
+impl OperationError {
+    #[must_use]
+    pub fn new(operation: Operation, items: Vec<EnrichedTrashItem>, error: TrashError) -> Self {
+        Self {
+            operation,
+            items,
+            error,
+        }
+    }
+
+    /// Whether the banner offers a retry (operation supports it and the error may be transient)
+    #[must_use]
+    pub fn is_retryable(&self) -> bool {
+        self.operation.is_retryable() && self.error.is_retryable()
+    }
+
+    /// Banner title: "Failed to restore notes.txt"
+    #[must_use]
+    pub fn title(&self) -> String {
+        if matches!(self.error, TrashError::PartialFailure { .. })
+            && self.operation == Operation::Empty
+        {
+            return fl!("trash-partially-emptied");
+        }
+
+        // Single items are named; `count` picks the plural form
+        let count = self.items.len();
+        let name = match self.items.as_slice() {
+            [enriched] => enriched.item.name.to_string_lossy().into_owned(),
+            _ => String::new(),
+        };
+
+        match self.operation {
+            Operation::Empty => fl!("failed-empty"),
+            Operation::Restore => fl!("failed-restore", count = count, name = name),
+            Operation::Delete => fl!("failed-delete", count = count, name = name),
+            Operation::Open => fl!("failed-open", count = count, name = name),
+            Operation::FileManager => fl!("failed-file-manager"),
+            Operation::Export => fl!("failed-export"),
+            Operation::Cleanup => fl!("failed-cleanup", count = count, name = name),
+        }
+    }
+
+    /// Reason a single item failed within a partial failure
+    #[must_use]
+    pub fn item_reason(&self, enriched: &EnrichedTrashItem) -> Option<&TrashError> {
+        let TrashError::PartialFailure { failed } = &self.error else {
+            return None;
+        };
+        failed
+            .iter()
+            .find(|failure| failure.item.id == enriched.item.id)
+            .map(|failure| &failure.error)
+    }
+
+    /// Removes one item (e.g. to retry it on its own)
+    ///
+    /// Returns `None` if the index is out of range.
+    pub fn take_item(&mut self, item_index: usize) -> Option<EnrichedTrashItem> {
+        if item_index >= self.items.len() {
+            return None;
+        }
+        let enriched = self.items.remove(item_index);
+        if let TrashError::PartialFailure { failed } = &mut self.error {
+            failed.retain(|failure| failure.item.id != enriched.item.id);
+        }
+        Some(enriched)
+    }
+}
+
+/// Pushes an error, keeping only the most recent `MAX_RECENT_ERRORS`
+pub fn push_recent(errors: &mut Vec<OperationError>, error: OperationError) {
+    eprintln!("❌ {}: {}", error.title(), error.error);
+    errors.push(error);
+    if errors.len() > MAX_RECENT_ERRORS {
+        errors.remove(0);
+    }
+}
@@ -0,0 +1,308 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Backend: Secure erase ("shred") before permanent deletion
+//!
+//! Overwrites the contents of regular files in place before they are unlinked,
+//! recursing through directories (symlinks are never followed).
+//!
+//! This only helps on storage that rewrites blocks in place. SSDs (wear
+//! leveling, over-provisioning) and copy-on-write filesystems (Btrfs, ZFS,
+//! bcachefs) write the new data elsewhere, so the old contents may survive in
+//! other blocks or snapshots. Full-disk encryption is the reliable option there.
+
+use crate::fl;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Seek, SeekFrom, Write};
+use std::os::unix::fs::MetadataExt;
+use std::path::{Path, PathBuf};
+
+/// User-facing caveat shown next to secure erase actions
+#[must_use]
+pub fn limitations() -> String {
+    fl!("secure-erase-limitations")
+}
+
+/// Passes used when the configured count is 0
+pub const DEFAULT_PASSES: u32 = 3;
+
+/// Upper bound on configured passes (Gutmann uses 35; more is pointless)
+pub const MAX_PASSES: u32 = 35;
+
+/// Write chunk size
+const CHUNK_SIZE: usize = 64 * 1024;
+
+/// Report progress at most every this many bytes (plus once per file pass)
+const PROGRESS_INTERVAL: u64 = 8 * 1024 * 1024;
+
+/// Copy-on-write filesystems where in-place overwrites are not reliable
+const COPY_ON_WRITE_FILESYSTEMS: &[&str] = &["btrfs", "zfs", "bcachefs"];
+
+/// Progress of a running secure erase
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SecureEraseProgress {
+    /// Name of the item being erased
+    pub current: String,
+    /// Bytes written so far (all passes)
+    pub bytes_done: u64,
+    /// Bytes to write in total (all passes)
+    pub bytes_total: u64,
+    /// Copy-on-write filesystem detected for the current item (e.g. "btrfs")
+    pub copy_on_write: Option<String>,
+}
+
+impl SecureEraseProgress {
+    /// Completed fraction in `0.0..=1.0`
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn fraction(&self) -> f32 {
+        if self.bytes_total == 0 {
+            return 1.0;
+        }
+        (self.bytes_done as f64 / self.bytes_total as f64).min(1.0) as f32
+    }
+}
+
+/// Clamps the configured pass count (0 = `DEFAULT_PASSES`)
+#[must_use]
+pub fn effective_passes(configured: u32) -> u32 {
+    match configured {
+        0 => DEFAULT_PASSES,
+        passes => passes.min(MAX_PASSES),
+    }
+}
+
+/// Bytes of regular file content under `path` (one pass)
+#[must_use]
+pub fn content_size(path: &Path) -> u64 {
+    let Ok(metadata) = fs::symlink_metadata(path) else {
+        return 0;
+    };
+
+    if metadata.is_file() {
+        metadata.len()
+    } else if metadata.is_dir() {
+        fs::read_dir(path)
+            .map(|entries| {
+                entries
+                    .filter_map(Result::ok)
+                    .map(|entry| content_size(&entry.path()))
+                    .sum()
+            })
+            .unwrap_or(0)
+    } else {
+        0 // Symlinks, sockets, devices: nothing to overwrite
+    }
+}
+
+/// Overwrites every regular file under `path` with `passes` passes
+///
+/// `on_progress` receives the number of bytes written since the last call.
+/// Nothing is written if any file under `path` is hard-linked elsewhere:
+/// overwriting it would destroy the live copy outside the trash.
+///
+/// # Errors
+///
+/// Returns the first I/O error (permissions, read-only filesystem, etc.), or
+/// an error naming the first hard-linked file
+pub fn overwrite(path: &Path, passes: u32, on_progress: &mut dyn FnMut(u64)) -> io::Result<()> {
+    refuse_hard_links(path)?;
+    overwrite_tree(path, passes, on_progress)
+}
+
+/// Fails if a regular file under `path` has more than one link
+fn refuse_hard_links(path: &Path) -> io::Result<()> {
+    let metadata = fs::symlink_metadata(path)?;
+
+    if metadata.is_dir() {
+        for entry in fs::read_dir(path)? {
+            refuse_hard_links(&entry?.path())?;
+        }
+    } else if metadata.is_file() && metadata.nlink() > 1 {
+        return Err(io::Error::other(format!(
+            "{} has {} hard links; overwriting it would also erase the other copies",
+            path.display(),
+            metadata.nlink()
+        )));
+    }
+
+    Ok(())
+}
+
+fn overwrite_tree(path: &Path, passes: u32, on_progress: &mut dyn FnMut(u64)) -> io::Result<()> {
+    let metadata = fs::symlink_metadata(path)?;
+
+    if metadata.is_dir() {
+        for entry in fs::read_dir(path)? {
+            overwrite_tree(&entry?.path(), passes, on_progress)?;
+        }
+        Ok(())
+    } else if metadata.is_file() {
+        overwrite_file(path, metadata, passes, on_progress)
+    } else {
+        Ok(())
+    }
+}
+
+fn overwrite_file(
+    path: &Path,
+    metadata: fs::Metadata,
+    passes: u32,
+    on_progress: &mut dyn FnMut(u64),
+) -> io::Result<()> {
+    // Trashed files may be read-only; we own them, so allow writing
+    let mut permissions = metadata.permissions();
+    if permissions.readonly() {
+        #[allow(clippy::permissions_set_readonly_false)]
+        permissions.set_readonly(false);
+        fs::set_permissions(path, permissions)?;
+    }
+
+    let mut file = OpenOptions::new().write(true).open(path)?;
+    let len = metadata.len();
+    let mut pattern = Pattern::new();
+
+    for pass in 0..passes {
+        // Random passes, last pass zeros (when there is more than one)
+        let zeros = passes > 1 && pass == passes - 1;
+        write_pass(&mut file, len, zeros, &mut pattern, on_progress)?;
+        file.sync_data()?;
+    }
+
+    Ok(())
+}
+
+fn write_pass(
+    file: &mut File,
+    len: u64,
+    zeros: bool,
+    pattern: &mut Pattern,
+    on_progress: &mut dyn FnMut(u64),
+) -> io::Result<()> {
+    let mut buffer = vec![0u8; CHUNK_SIZE];
+    let mut written = 0u64;
+    let mut unreported = 0u64;
+
+    file.seek(SeekFrom::Start(0))?;
+    while written < len {
+        let chunk = usize::try_from(len - written).map_or(CHUNK_SIZE, |left| left.min(CHUNK_SIZE));
+        if !zeros {
+            pattern.fill(&mut buffer[..chunk]);
+        }
+        file.write_all(&buffer[..chunk])?;
+
+        written += chunk as u64;
+        unreported += chunk as u64;
+        if unreported >= PROGRESS_INTERVAL {
+            on_progress(unreported);
+            unreported = 0;
+        }
+    }
+    file.flush()?;
+    on_progress(unreported);
+
+    Ok(())
+}
+
+/// Copy-on-write filesystem type holding `path`, if any
+///
+/// Resolved from `/proc/self/mounts` (longest matching mount point).
+#[must_use]
+pub fn copy_on_write_filesystem(path: &Path) -> Option<String> {
+    let path = path.canonicalize().ok()?;
+    let mounts = fs::read_to_string("/proc/self/mounts").ok()?;
+
+    mounts
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.split_whitespace();
+            let mount_point = PathBuf::from(unescape_mount(fields.nth(1)?));
+            let fs_type = fields.next()?;
+            path.starts_with(&mount_point)
+                .then(|| (mount_point.components().count(), fs_type.to_string()))
+        })
+        .max_by_key(|(depth, _)| *depth)
+        .map(|(_, fs_type)| fs_type)
+        .filter(|fs_type| COPY_ON_WRITE_FILESYSTEMS.contains(&fs_type.as_str()))
+}
+
+/// Decodes octal escapes used in `/proc/self/mounts` (`\040` = space)
+fn unescape_mount(field: &str) -> String {
+    let mut result = String::with_capacity(field.len());
+    let mut chars = field.chars();
+
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            let code: String = chars.by_ref().take(3).collect();
+            match u8::from_str_radix(&code, 8) {
+                Ok(byte) => result.push(char::from(byte)),
+                Err(_) => {
+                    result.push(c);
+                    result.push_str(&code);
+                }
+            }
+        } else {
+            result.push(c);
+        }
+    }
+
+    result
+}
+
+/// Fast non-cryptographic byte stream (xorshift64*) for overwrite passes
+///
+/// The goal is to replace the contents, not to produce secrets; seeding from
+/// the clock keeps passes from being identical.
+struct Pattern(u64);
+
+impl Pattern {
+    fn new() -> Self {
+        let seed = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0x9E37_79B9_7F4A_7C15, |elapsed| {
+                u64::try_from(elapsed.as_nanos() & u128::from(u64::MAX)).unwrap_or(1)
+            });
+        Self(seed | 1)
+    }
+
+    fn fill(&mut self, buffer: &mut [u8]) {
+        for chunk in buffer.chunks_mut(8) {
+            self.0 ^= self.0 >> 12;
+            self.0 ^= self.0 << 25;
+            self.0 ^= self.0 >> 27;
+            let bytes = self.0.wrapping_mul(0x2545_F491_4F6C_DD1D).to_le_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn overwrite_replaces_contents() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("secret");
+        fs::write(&path, b"secret data").unwrap();
+
+        overwrite(&path, 2, &mut |_| {}).unwrap();
+
+        assert_eq!(fs::read(&path).unwrap(), vec![0u8; 11]);
+    }
+
+    #[test]
+    fn overwrite_refuses_hard_linked_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let trashed = dir.path().join("trashed");
+        fs::create_dir(&trashed).unwrap();
+        fs::write(trashed.join("a"), b"keep").unwrap();
+        fs::write(trashed.join("b"), b"live").unwrap();
+        fs::hard_link(trashed.join("b"), dir.path().join("live-copy")).unwrap();
+
+        assert!(overwrite(&trashed, 1, &mut |_| {}).is_err());
+
+        // Nothing under the item was touched
+        assert_eq!(fs::read(trashed.join("a")).unwrap(), b"keep");
+        assert_eq!(fs::read(dir.path().join("live-copy")).unwrap(), b"live");
+    }
+}
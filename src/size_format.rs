@@ -0,0 +1,253 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Human-readable sizes
+//!
+//! Items keep raw byte counts; this module turns them into localized strings
+//! in the unit system chosen in `Config::size_units`:
+//! - IEC: powers of 1024 (`KiB`, `MiB`, …), what file managers traditionally show
+//! - SI: powers of 1000 (`kB`, `MB`, …), what disk vendors and GNOME Files show
+
+use crate::{fl, i18n};
+use serde::{Deserialize, Serialize};
+
+/// Largest unit exponent (TiB / TB)
+const MAX_EXPONENT: usize = 4;
+
+/// Unit system for sizes
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SizeUnits {
+    /// Powers of 1024: KiB, MiB, GiB, TiB
+    #[default]
+    Iec,
+    /// Powers of 1000: kB, MB, GB, TB
+    Si,
+}
+
+impl SizeUnits {
+    pub const ALL: [Self; 2] = [Self::Iec, Self::Si];
+
+    #[must_use]
+    pub fn name(self) -> String {
+        match self {
+            Self::Iec => fl!("size-units-iec"),
+            Self::Si => fl!("size-units-si"),
+        }
+    }
+
+    const fn base(self) -> u64 {
+        match self {
+            Self::Iec => 1024,
+            Self::Si => 1000,
+        }
+    }
+}
+
+/// Formats `bytes` in `units` with locale separators (`1.5 MiB`, `512 kB`)
+///
+/// Values below one unit are shown in bytes. Larger values get one decimal,
+/// dropped when it rounds to zero; a value that rounds up to the next unit
+/// (`1023.96 KiB`) is shown in that unit (`1 MiB`).
+#[must_use]
+#[allow(clippy::cast_precision_loss)]
+pub fn format(bytes: u64, units: SizeUnits) -> String {
+    let base = units.base();
+    if bytes < base {
+        return fl!("size-bytes", value = i18n::format_count(bytes));
+    }
+
+    let base = base as f64;
+    let mut value = bytes as f64 / base;
+    let mut exponent = 1;
+    while round_tenths(value) >= base && exponent < MAX_EXPONENT {
+        value /= base;
+        exponent += 1;
+    }
+
+    let value = round_tenths(value);
+    let decimals = if value.fract() == 0.0 { 0 } else { 1 };
+    let value = i18n::format_decimal(value, decimals);
+
+    match (units, exponent) {
+        (SizeUnits::Iec, 1) => fl!("size-kib", value = value),
+        (SizeUnits::Iec, 2) => fl!("size-mib", value = value),
+        (SizeUnits::Iec, 3) => fl!("size-gib", value = value),
+        (SizeUnits::Iec, _) => fl!("size-tib", value = value),
+        (SizeUnits::Si, 1) => fl!("size-kb", value = value),
+        (SizeUnits::Si, 2) => fl!("size-mb", value = value),
+        (SizeUnits::Si, 3) => fl!("size-gb", value = value),
+        (SizeUnits::Si, _) => fl!("size-tb", value = value),
+    }
+}
+
+/// Rounds to one decimal, the precision sizes are displayed with
+fn round_tenths(value: f64) -> f64 {
+    (value * 10.0).round() / 10.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KIB: u64 = 1024;
+    const MIB: u64 = KIB * 1024;
+    const GIB: u64 = MIB * 1024;
+    const TIB: u64 = GIB * 1024;
+
+    /// `format` without Fluent's bidi isolation marks (fallback language: en)
+    fn plain(bytes: u64, units: SizeUnits) -> String {
+        format(bytes, units).replace(['\u{2068}', '\u{2069}'], "")
+    }
+
+    #[test]
+    fn iec_bytes_below_one_kib() {
+        assert_eq!(plain(0, SizeUnits::Iec), "0 B");
+        assert_eq!(plain(999, SizeUnits::Iec), "999 B");
+        assert_eq!(plain(1000, SizeUnits::Iec), "1,000 B");
+        assert_eq!(plain(1023, SizeUnits::Iec), "1,023 B");
+        assert_eq!(plain(1024, SizeUnits::Iec), "1 KiB");
+        assert_eq!(plain(1536, SizeUnits::Iec), "1.5 KiB");
+    }
+
+    #[test]
+    fn iec_rolls_over_when_rounding_reaches_the_next_unit() {
+        assert_eq!(plain(MIB - 103, SizeUnits::Iec), "1,023.9 KiB");
+        assert_eq!(plain(MIB - 1, SizeUnits::Iec), "1 MiB");
+        assert_eq!(plain(MIB, SizeUnits::Iec), "1 MiB");
+        assert_eq!(plain(GIB - 1, SizeUnits::Iec), "1 GiB");
+        assert_eq!(plain(GIB, SizeUnits::Iec), "1 GiB");
+        assert_eq!(plain(TIB - 1, SizeUnits::Iec), "1 TiB");
+        assert_eq!(plain(TIB, SizeUnits::Iec), "1 TiB");
+    }
+
+    #[test]
+    fn iec_stays_in_tib_above_it() {
+        assert_eq!(plain(1024 * TIB, SizeUnits::Iec), "1,024 TiB");
+        assert_eq!(plain(u64::MAX, SizeUnits::Iec), "16,777,216 TiB");
+    }
+
+    #[test]
+    fn si_bytes_below_one_kb() {
+        assert_eq!(plain(0, SizeUnits::Si), "0 B");
+        assert_eq!(plain(999, SizeUnits::Si), "999 B");
+        assert_eq!(plain(1000, SizeUnits::Si), "1 kB");
+        assert_eq!(plain(1023, SizeUnits::Si), "1 kB");
+        assert_eq!(plain(1024, SizeUnits::Si), "1 kB");
+        assert_eq!(plain(1500, SizeUnits::Si), "1.5 kB");
+    }
+
+    #[test]
+    fn si_rolls_over_when_rounding_reaches_the_next_unit() {
+        assert_eq!(plain(999_900, SizeUnits::Si), "999.9 kB");
+        assert_eq!(plain(999_999, SizeUnits::Si), "1 MB");
+        assert_eq!(plain(1_000_000, SizeUnits::Si), "1 MB");
+        assert_eq!(plain(999_999_999, SizeUnits::Si), "1 GB");
+        assert_eq!(plain(1_000_000_000, SizeUnits::Si), "1 GB");
+        assert_eq!(plain(999_999_999_999, SizeUnits::Si), "1 TB");
+        assert_eq!(plain(1_000_000_000_000, SizeUnits::Si), "1 TB");
+    }
+
+    #[test]
+    fn si_stays_in_tb_above_it() {
+        assert_eq!(plain(1_000_000_000_000_000, SizeUnits::Si), "1,000 TB");
+        assert_eq!(plain(u64::MAX, SizeUnits::Si), "18,446,744.1 TB");
+    }
+
+    // Property checks over a seeded sample of `u64` (every magnitude) plus the
+    // values around each unit boundary
+
+    /// Sizes to check: boundaries ± 2, `u64::MAX` and pseudo-random values
+    fn samples() -> Vec<u64> {
+        let mut samples = vec![0, 1, u64::MAX - 1, u64::MAX];
+        for base in [1000_u64, 1024] {
+            for exponent in 1..=6 {
+                let boundary = base.pow(exponent);
+                samples.extend((boundary - 2..=boundary + 2).chain([
+                    // Where one decimal rounds up to the next unit
+                    boundary - boundary / base / 20,
+                    boundary - boundary / base / 20 - 1,
+                ]));
+            }
+        }
+
+        // xorshift64*, fixed seed; the shift spreads values over all magnitudes
+        let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+        for _ in 0..20_000 {
+            state ^= state >> 12;
+            state ^= state << 25;
+            state ^= state >> 27;
+            let random = state.wrapping_mul(0x2545_f491_4f6c_dd1d);
+            samples.push(random >> (random % 64));
+        }
+
+        samples.sort_unstable();
+        samples.dedup();
+        samples
+    }
+
+    /// Reads a formatted size back as (bytes, unit exponent)
+    fn parse(formatted: &str) -> (f64, i32) {
+        let (value, unit) = formatted.split_once(' ').unwrap();
+        let exponent = ["B", "KiB", "MiB", "GiB", "TiB"]
+            .iter()
+            .chain(&["B", "kB", "MB", "GB", "TB"])
+            .position(|known| *known == unit)
+            .unwrap_or_else(|| panic!("unknown unit in {formatted:?}"))
+            % 5;
+        let base = if unit.contains('i') {
+            1024_f64
+        } else {
+            1000_f64
+        };
+        let value: f64 = value.replace(',', "").parse().unwrap();
+        let exponent = i32::try_from(exponent).unwrap();
+        (value * base.powi(exponent), exponent)
+    }
+
+    #[test]
+    #[allow(clippy::cast_precision_loss)]
+    fn formatted_sizes_stay_close_to_the_byte_count() {
+        for units in SizeUnits::ALL {
+            for bytes in samples() {
+                let formatted = plain(bytes, units);
+                let (value, exponent) = parse(&formatted);
+                if exponent == 0 {
+                    assert_eq!(value, bytes as f64, "{formatted}");
+                } else {
+                    // One decimal of at least one unit: at most 5% off
+                    let error = (value - bytes as f64).abs() / bytes as f64;
+                    assert!(error <= 0.05, "{bytes} → {formatted}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn formatted_sizes_are_monotonic() {
+        for units in SizeUnits::ALL {
+            let values: Vec<(u64, f64)> = samples()
+                .into_iter()
+                .map(|bytes| (bytes, parse(&plain(bytes, units)).0))
+                .collect();
+            for pair in values.windows(2) {
+                let ((smaller, before), (larger, after)) = (pair[0], pair[1]);
+                assert!(
+                    before <= after,
+                    "{smaller} → {}, {larger} → {}",
+                    plain(smaller, units),
+                    plain(larger, units)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn si_never_uses_a_smaller_unit_than_iec() {
+        // 1000ⁿ < 1024ⁿ, so SI reaches each unit first (both stop at tera)
+        for bytes in samples() {
+            let (iec, iec_exponent) = parse(&plain(bytes, SizeUnits::Iec));
+            let (si, si_exponent) = parse(&plain(bytes, SizeUnits::Si));
+            assert!(si_exponent >= iec_exponent, "{bytes}");
+            assert!((si - iec).abs() <= 0.1 * si.max(iec), "{bytes}");
+        }
+    }
+}
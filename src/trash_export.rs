@@ -0,0 +1,189 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Backend: Machine-readable export of trash contents
+//!
+//! Serializes the enriched item list to JSON or CSV (RFC 4180) for audits.
+//! Used by the popup ("Export list…") and the CLI (`list --json`, `export`).
+
+use crate::size_format::SizeUnits;
+use crate::trash_item_metadata::EnrichedTrashItem;
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+
+/// CSV column order (matches `ExportRecord` field order)
+const CSV_HEADER: [&str; 9] = [
+    "id",
+    "name",
+    "original_path",
+    "time_deleted",
+    "deleted_at",
+    "size_bytes",
+    "size",
+    "mime",
+    "bin",
+];
+
+/// Export file format
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Json,
+    Csv,
+}
+
+impl ExportFormat {
+    /// Picks the format from a file extension (`.csv` → CSV, anything else → JSON)
+    #[must_use]
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(std::ffi::OsStr::to_str) {
+            Some(extension) if extension.eq_ignore_ascii_case("csv") => Self::Csv,
+            _ => Self::Json,
+        }
+    }
+
+    /// File extension without dot
+    #[must_use]
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Json => "json",
+            Self::Csv => "csv",
+        }
+    }
+}
+
+/// One exported trash item
+#[derive(Debug, Clone, Serialize)]
+pub struct ExportRecord {
+    /// Trash item id (`.trashinfo` path)
+    pub id: String,
+    pub name: String,
+    pub original_path: String,
+    /// Deletion time (Unix seconds)
+    pub time_deleted: i64,
+    /// Deletion time (RFC 3339, local time zone)
+    pub deleted_at: String,
+    /// Size in bytes (folders: recursive total)
+    pub size_bytes: Option<u64>,
+    /// Human-readable size ("5 MiB", "3 items")
+    pub size: String,
+    pub mime: String,
+    /// Trash bin holding the item
+    pub bin: Option<String>,
+}
+
+impl ExportRecord {
+    /// Record for `enriched`, with `size` formatted in `units`
+    #[must_use]
+    pub fn new(enriched: &EnrichedTrashItem, units: SizeUnits) -> Self {
+        Self {
+            id: enriched.item.id.to_string_lossy().into_owned(),
+            name: enriched.item.name.to_string_lossy().into_owned(),
+            original_path: enriched.item.original_path().to_string_lossy().into_owned(),
+            time_deleted: enriched.item.time_deleted,
+            deleted_at: chrono::DateTime::from_timestamp(enriched.item.time_deleted, 0)
+                .map(|time| time.with_timezone(&chrono::Local).to_rfc3339())
+                .unwrap_or_default(),
+            size_bytes: enriched.size_bytes,
+            size: enriched.size_display(units),
+            mime: enriched.mime.essence_str().to_string(),
+            bin: enriched
+                .trash_bin()
+                .map(|bin| bin.to_string_lossy().into_owned()),
+        }
+    }
+}
+
+/// Converts enriched items to export records (same order)
+#[must_use]
+pub fn records(items: &[EnrichedTrashItem], units: SizeUnits) -> Vec<ExportRecord> {
+    items
+        .iter()
+        .map(|enriched| ExportRecord::new(enriched, units))
+        .collect()
+}
+
+/// Serializes items as a pretty-printed JSON array
+///
+/// # Errors
+///
+/// Returns error if serialization fails
+pub fn to_json(items: &[EnrichedTrashItem], units: SizeUnits) -> Result<String, serde_json::Error> {
+    serde_json::to_string_pretty(&records(items, units))
+}
+
+/// Serializes items as CSV with a header row
+#[must_use]
+pub fn to_csv(items: &[EnrichedTrashItem], units: SizeUnits) -> String {
+    let mut csv = CSV_HEADER.join(",");
+    csv.push_str("\r\n");
+
+    for record in records(items, units) {
+        let fields = [
+            record.id,
+            record.name,
+            record.original_path,
+            record.time_deleted.to_string(),
+            record.deleted_at,
+            record
+                .size_bytes
+                .map(|bytes| bytes.to_string())
+                .unwrap_or_default(),
+            record.size,
+            record.mime,
+            record.bin.unwrap_or_default(),
+        ];
+        let row: Vec<String> = fields.iter().map(|field| csv_field(field)).collect();
+        csv.push_str(&row.join(","));
+        csv.push_str("\r\n");
+    }
+
+    csv
+}
+
+/// Quotes a CSV field when it contains separators, quotes or line breaks
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\r', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Serializes items in the given format
+///
+/// # Errors
+///
+/// Returns error if JSON serialization fails
+pub fn to_string(
+    items: &[EnrichedTrashItem],
+    format: ExportFormat,
+    units: SizeUnits,
+) -> Result<String, String> {
+    match format {
+        ExportFormat::Json => to_json(items, units).map_err(|e| e.to_string()),
+        ExportFormat::Csv => Ok(to_csv(items, units)),
+    }
+}
+
+/// Writes the export to `path`
+///
+/// # Errors
+///
+/// Returns error if serialization or writing fails
+pub async fn export_to_file(
+    items: Vec<EnrichedTrashItem>,
+    format: ExportFormat,
+    units: SizeUnits,
+    path: PathBuf,
+) -> Result<PathBuf, String> {
+    tokio::task::spawn_blocking(move || {
+        let contents = to_string(&items, format, units)?;
+        std::fs::write(&path, contents)
+            .map_err(|e| format!("Failed to write {}: {e}", path.display()))?;
+        Ok(path)
+    })
+    .await
+    .map_err(|e| {
+        eprintln!("Failed to spawn export task: {e}");
+        format!("Task spawn failed: {e}")
+    })?
+}
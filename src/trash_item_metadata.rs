@@ -3,22 +3,32 @@
 //! Trash Item Metadata Module
 //!
 //! Pre-computes and caches metadata for trash items to avoid filesystem I/O during rendering.
-//! Provides enriched items with size strings, MIME types, and sorted ordering (folders first).
+//! Provides enriched items with raw sizes, MIME types, and sorted ordering (folders first).
 
+use crate::fl;
+use crate::size_format::{self, SizeUnits};
 use std::cmp::Ordering;
+use std::ffi::OsStr;
+use std::os::unix::ffi::OsStrExt;
+use std::path::{Path, PathBuf};
 
 /// Enriched trash item with pre-computed metadata
 ///
 /// Avoids filesystem access during UI rendering by caching:
-/// - Formatted size string
+/// - Size in bytes and folder entry count (formatted at render time)
 /// - MIME type (for icon resolution)
 /// - Is directory flag (for sorting)
+/// - Location inside the trash bin (for previews)
 #[derive(Debug, Clone)]
 pub struct EnrichedTrashItem {
     /// Original trash item from trash-rs
     pub item: trash::TrashItem,
-    /// Pre-formatted size string ("5.0 MB", "3 items", etc.)
-    pub size_display: String,
+    /// Path of the item inside `<bin>/files` (None if not found on disk)
+    pub trash_path: Option<PathBuf>,
+    /// Size in bytes (folders: recursive total; None for missing items)
+    pub size_bytes: Option<u64>,
+    /// Direct children of a folder (None for files and missing items)
+    pub entry_count: Option<usize>,
     /// MIME type for icon resolution (uses cosmic-files cache)
     pub mime: mime_guess::Mime,
     /// Whether this item is a directory (for sorting)
@@ -33,17 +43,66 @@ impl EnrichedTrashItem {
     /// MIME type is detected but icon is resolved lazily via cached `mime_icon()`.
     #[must_use]
     pub fn from_trash_item(item: trash::TrashItem) -> Self {
-        let (size_display, is_dir) = compute_size(&item);
-        let mime = compute_mime(&item, is_dir);
+        let trash_path = locate_in_trash(&item);
+        let (size_bytes, entry_count, is_dir) = compute_size(trash_path.as_deref());
+        let mime = compute_mime(trash_path.as_deref(), is_dir);
 
         Self {
             item,
-            size_display,
+            trash_path,
+            size_bytes,
+            entry_count,
             mime,
             is_dir,
         }
     }
 
+    /// Localized size column: item count for folders, size in `units` for files
+    #[must_use]
+    pub fn size_display(&self, units: SizeUnits) -> String {
+        match (self.entry_count, self.size_bytes) {
+            (Some(count), _) => fl!("folder-items", count = count),
+            (None, Some(bytes)) => size_format::format(bytes, units),
+            (None, None) => "-".to_string(),
+        }
+    }
+
+    /// Trash bin holding this item (the directory containing `files/` and `info/`)
+    #[must_use]
+    pub fn trash_bin(&self) -> Option<&Path> {
+        self.trash_path.as_deref()?.parent()?.parent()
+    }
+
+    /// Whether the name or original location contains `query` (case-insensitive)
+    ///
+    /// An empty query matches everything.
+    #[must_use]
+    pub fn matches(&self, query: &str) -> bool {
+        let query = query.trim().to_lowercase();
+        query.is_empty()
+            || self
+                .item
+                .name
+                .to_string_lossy()
+                .to_lowercase()
+                .contains(&query)
+            || self
+                .item
+                .original_parent
+                .to_string_lossy()
+                .to_lowercase()
+                .contains(&query)
+    }
+
+    /// The `count` most recently deleted items, newest first (ignores the list order)
+    #[must_use]
+    pub fn most_recent(items: &[Self], count: usize) -> Vec<&Self> {
+        let mut recent: Vec<&Self> = items.iter().collect();
+        recent.sort_by_key(|enriched| std::cmp::Reverse(enriched.item.time_deleted));
+        recent.truncate(count);
+        recent
+    }
+
     /// Sorts items: folders first (alphabetical), then files (alphabetical)
     ///
     /// # Arguments
@@ -73,79 +132,116 @@ impl EnrichedTrashItem {
     }
 }
 
-/// Computes size display string for trash item
+/// Finds the item inside the `files` directory of its trash bin
 ///
-/// Uses metadata.is_dir() for correct detection (not path.is_dir())
-/// Works for ALL file types - icons handled by cosmic-files mime_icon()
-#[allow(clippy::cast_precision_loss)]
-fn compute_size(item: &trash::TrashItem) -> (String, bool) {
-    let Ok(trash_folders) = trash::os_limited::trash_folders() else {
-        return ("-".to_string(), false);
-    };
+/// The id is the item's `.trashinfo` path (`<bin>/info/X.trashinfo`), and its
+/// data lives at `<bin>/files/X`. `X` is not always the display name: trashing
+/// a second `report.pdf` stores it as `report.pdf.1`, so resolving by name would
+/// find the other item.
+pub fn locate_in_trash(item: &trash::TrashItem) -> Option<PathBuf> {
+    trash_path_for_id(Path::new(&item.id)).filter(|path| std::fs::symlink_metadata(path).is_ok())
+}
 
-    // Try ALL trash folders until we find the file
-    let mut found_metadata = None;
-    let mut found_path = None;
-    for folder in trash_folders.iter() {
-        let path = folder.join("files").join(&item.name);
-        if let Ok(meta) = std::fs::metadata(&path) {
-            found_metadata = Some(meta);
-            found_path = Some(path);
-            break;
-        }
+/// `<bin>/info/X.trashinfo` → `<bin>/files/X` (None for other paths)
+fn trash_path_for_id(info: &Path) -> Option<PathBuf> {
+    let stored_name = info
+        .file_name()?
+        .as_bytes()
+        .strip_suffix(b".trashinfo")
+        .filter(|name| !name.is_empty())?;
+    let info_dir = info.parent()?;
+    if info_dir.file_name()? != "info" {
+        return None;
     }
 
-    let Some(metadata) = found_metadata else {
-        return ("-".to_string(), false);
+    Some(
+        info_dir
+            .parent()?
+            .join("files")
+            .join(OsStr::from_bytes(stored_name)),
+    )
+}
+
+/// Computes byte count (and entry count for folders) for trash item
+///
+/// Uses metadata.is_dir() for correct detection (not path.is_dir())
+/// Works for ALL file types - icons handled by cosmic-files mime_icon()
+fn compute_size(trash_path: Option<&Path>) -> (Option<u64>, Option<usize>, bool) {
+    let Some(metadata) = trash_path.and_then(|path| std::fs::metadata(path).ok()) else {
+        return (None, None, false);
     };
 
     if metadata.is_dir() {
         // Folders: count items
-        let count = found_path
+        let count = trash_path
             .and_then(|p| std::fs::read_dir(p).ok())
             .map(std::iter::Iterator::count)
             .unwrap_or(0);
-        (format!("{count} items"), true)
+        let bytes = trash_path.map_or(0, dir_size);
+        (Some(bytes), Some(count), true)
     } else {
-        // Files (ALL types): format bytes
-        let bytes = metadata.len();
-        let size_str = if bytes == 0 {
-            "0 B".to_string()
-        } else if bytes < 1024 {
-            format!("{bytes} B")
-        } else if bytes < 1024 * 1024 {
-            format!("{:.1} KB", bytes as f64 / 1024.0)
-        } else if bytes < 1024 * 1024 * 1024 {
-            format!("{:.1} MB", bytes as f64 / (1024.0 * 1024.0))
-        } else {
-            format!("{:.1} GB", bytes as f64 / (1024.0 * 1024.0 * 1024.0))
-        };
-        (size_str, false)
+        // Files (ALL types): raw bytes, formatted when rendered
+        (Some(metadata.len()), None, false)
     }
 }
 
+/// Recursive size of a directory in bytes (symlinks are not followed)
+fn dir_size(path: &Path) -> u64 {
+    let Ok(entries) = std::fs::read_dir(path) else {
+        return 0;
+    };
+
+    entries
+        .filter_map(Result::ok)
+        .map(|entry| match entry.metadata() {
+            Ok(metadata) if metadata.is_dir() => dir_size(&entry.path()),
+            Ok(metadata) => metadata.len(),
+            Err(_) => 0,
+        })
+        .sum()
+}
+
 /// Computes MIME type for trash item (used with cosmic-files mime_icon cache)
 ///
 /// Returns MIME type for efficient icon resolution via `mime_icon()` cache.
 /// Directories return "inode/directory" MIME type.
-fn compute_mime(item: &trash::TrashItem, is_dir: bool) -> mime_guess::Mime {
+fn compute_mime(trash_path: Option<&Path>, is_dir: bool) -> mime_guess::Mime {
     if is_dir {
         return "inode/directory".parse().unwrap();
     }
 
-    // Try ALL trash folders until we find the file
-    let Ok(trash_folders) = trash::os_limited::trash_folders() else {
-        return mime_guess::mime::TEXT_PLAIN;
-    };
+    match trash_path {
+        // Use cosmic-files mime detection (handles metadata and content-based detection)
+        Some(path) => crate::mime_icon::mime_for_path(path, None, false),
+        // Fallback if file not found in any folder
+        None => mime_guess::mime::TEXT_PLAIN,
+    }
+}
 
-    for folder in trash_folders.iter() {
-        let trash_path = folder.join("files").join(&item.name);
-        if trash_path.exists() {
-            // Use cosmic-files mime detection (handles metadata and content-based detection)
-            return crate::mime_icon::mime_for_path(&trash_path, None, false);
-        }
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn trash_path_uses_stored_name_from_id() {
+        assert_eq!(
+            trash_path_for_id(Path::new(
+                "/home/u/.local/share/Trash/info/report.pdf.1.trashinfo"
+            )),
+            Some(PathBuf::from(
+                "/home/u/.local/share/Trash/files/report.pdf.1"
+            ))
+        );
+        assert_eq!(
+            trash_path_for_id(Path::new("/mnt/usb/.Trash-1000/info/a b.trashinfo")),
+            Some(PathBuf::from("/mnt/usb/.Trash-1000/files/a b"))
+        );
     }
 
-    // Fallback if file not found in any folder
-    mime_guess::mime::TEXT_PLAIN
+    #[test]
+    fn trash_path_rejects_non_trashinfo_ids() {
+        assert_eq!(trash_path_for_id(Path::new("/tmp/info/file.txt")), None);
+        assert_eq!(trash_path_for_id(Path::new("/tmp/other/x.trashinfo")), None);
+        assert_eq!(trash_path_for_id(Path::new("/tmp/info/.trashinfo")), None);
+    }
 }
@@ -5,6 +5,10 @@
 //! Following cosmic-files patterns for non-blocking trash operations.
 //! All trash-rs calls are wrapped in `spawn_blocking` to prevent blocking the async runtime.
 
+use crate::config;
+use crate::error::{ItemFailure, TrashError};
+use crate::secure_erase::{self, SecureEraseProgress};
+use crate::trash_item_metadata;
 use std::path::PathBuf;
 
 /// Lists all items currently in the trash
@@ -12,57 +16,52 @@ use std::path::PathBuf;
 /// # Errors
 ///
 /// Returns error if trash listing fails (permissions, corrupted trash, etc.)
-pub async fn list_items() -> Result<Vec<trash::TrashItem>, trash::Error> {
+pub async fn list_items() -> Result<Vec<trash::TrashItem>, TrashError> {
     tokio::task::spawn_blocking(trash::os_limited::list)
-        .await
-        .map_err(|e| {
-            eprintln!("Failed to spawn list_items task: {e}");
-            trash::Error::Unknown {
-                description: format!("Task spawn failed: {e}"),
-            }
-        })?
+        .await?
+        .map_err(TrashError::from)
 }
 
-/// Empties the entire trash (permanently deletes all items)
+/// Empties the trash (permanently deletes all items except `pinned`)
 ///
 /// Operations run in background via `spawn_blocking`. Items are deleted sequentially.
+/// `pinned` holds `.trashinfo` paths (see `Config::pinned_items`).
 ///
 /// # Errors
 ///
 /// Returns error if:
 /// - Listing trash items fails
-/// - Any item deletion fails (partial failures collected and reported)
-pub async fn empty_trash() -> Result<(), trash::Error> {
-    tokio::task::spawn_blocking(|| {
-        let items = trash::os_limited::list()?;
-        let mut errors = Vec::new();
+/// - Any item deletion fails (`TrashError::PartialFailure` lists each failed item)
+pub async fn empty_trash(pinned: Vec<String>) -> Result<(), TrashError> {
+    tokio::task::spawn_blocking(move || {
+        let items = unpinned(trash::os_limited::list()?, &pinned);
+        let mut failed = Vec::new();
 
         for item in items {
-            if let Err(e) = trash::os_limited::purge_all([item]) {
-                errors.push(e);
+            if let Err(e) = trash::os_limited::purge_all([item.clone()]) {
+                failed.push(ItemFailure {
+                    item,
+                    error: TrashError::from(e),
+                });
             }
         }
 
         // Report partial failures
-        if !errors.is_empty() {
-            eprintln!("Failed to purge {} items during empty_trash", errors.len());
-            for err in &errors {
-                eprintln!("  - {err}");
+        if !failed.is_empty() {
+            eprintln!("Failed to purge {} items during empty_trash", failed.len());
+            for failure in &failed {
+                eprintln!(
+                    "  - {}: {}",
+                    failure.item.name.to_string_lossy(),
+                    failure.error
+                );
             }
-            return Err(trash::Error::Unknown {
-                description: format!("Failed to delete {} items", errors.len()),
-            });
+            return Err(TrashError::PartialFailure { failed });
         }
 
         Ok(())
     })
-    .await
-    .map_err(|e| {
-        eprintln!("Failed to spawn empty_trash task: {e}");
-        trash::Error::Unknown {
-            description: format!("Task spawn failed: {e}"),
-        }
-    })?
+    .await?
 }
 
 /// Restores a trash item to its original location
@@ -73,20 +72,15 @@ pub async fn empty_trash() -> Result<(), trash::Error> {
 /// - Original path no longer exists
 /// - Permissions prevent restoration
 /// - File conflicts at original location
-pub async fn restore_item(item: trash::TrashItem) -> Result<PathBuf, trash::Error> {
+pub async fn restore_item(item: trash::TrashItem) -> Result<PathBuf, TrashError> {
     let original_path = item.original_path();
 
     tokio::task::spawn_blocking(move || {
         trash::os_limited::restore_all([item])?;
         Ok(original_path)
     })
-    .await
-    .map_err(|e| {
-        eprintln!("Failed to spawn restore_item task: {e}");
-        trash::Error::Unknown {
-            description: format!("Task spawn failed: {e}"),
-        }
-    })?
+    .await?
+    .map_err(TrashError::from)
 }
 
 /// Permanently deletes a trash item (cannot be undone)
@@ -94,13 +88,187 @@ pub async fn restore_item(item: trash::TrashItem) -> Result<PathBuf, trash::Erro
 /// # Errors
 ///
 /// Returns error if deletion fails (permissions, locked file, etc.)
-pub async fn delete_item(item: trash::TrashItem) -> Result<(), trash::Error> {
+pub async fn delete_item(item: trash::TrashItem) -> Result<(), TrashError> {
     tokio::task::spawn_blocking(move || trash::os_limited::purge_all([item]))
-        .await
-        .map_err(|e| {
-            eprintln!("Failed to spawn delete_item task: {e}");
-            trash::Error::Unknown {
-                description: format!("Task spawn failed: {e}"),
-            }
-        })?
+        .await?
+        .map_err(TrashError::from)
+}
+
+/// Finds trash items by id (the `.trashinfo` path on freedesktop systems)
+///
+/// Unknown ids are ignored; callers compare lengths to detect missing items.
+///
+/// # Errors
+///
+/// Returns error if trash listing fails
+pub async fn find_items(ids: Vec<String>) -> Result<Vec<trash::TrashItem>, TrashError> {
+    let items = list_items().await?;
+
+    Ok(items
+        .into_iter()
+        .filter(|item| {
+            ids.iter()
+                .any(|id| item.id.to_string_lossy() == id.as_str())
+        })
+        .collect())
+}
+
+/// Restores several trash items to their original locations
+///
+/// # Errors
+///
+/// Returns error on the first failed restore (collision, permissions, etc.)
+pub async fn restore_items(items: Vec<trash::TrashItem>) -> Result<Vec<PathBuf>, TrashError> {
+    let original_paths = items.iter().map(trash::TrashItem::original_path).collect();
+
+    tokio::task::spawn_blocking(move || {
+        trash::os_limited::restore_all(items)?;
+        Ok(original_paths)
+    })
+    .await?
+    .map_err(TrashError::from)
+}
+
+/// Permanently deletes several trash items (cannot be undone)
+///
+/// # Errors
+///
+/// Returns error if deletion fails (permissions, locked file, etc.)
+pub async fn delete_items(items: Vec<trash::TrashItem>) -> Result<(), TrashError> {
+    tokio::task::spawn_blocking(move || trash::os_limited::purge_all(items))
+        .await?
+        .map_err(TrashError::from)
+}
+
+/// Moves files or folders to the trash
+///
+/// # Errors
+///
+/// Returns error if any path cannot be trashed (missing, permissions, root, etc.)
+pub async fn put_items(paths: Vec<PathBuf>) -> Result<(), TrashError> {
+    tokio::task::spawn_blocking(move || trash::delete_all(paths))
+        .await?
+        .map_err(TrashError::from)
+}
+
+/// Overwrites (see `secure_erase`) and then permanently deletes trash items
+///
+/// `passes` is clamped by `secure_erase::effective_passes`. `on_progress` is
+/// called from the blocking task as data is written.
+///
+/// # Errors
+///
+/// Returns the item's error for a single item, or `TrashError::PartialFailure`
+/// listing every item that could not be overwritten or deleted
+pub async fn secure_delete_items(
+    items: Vec<trash::TrashItem>,
+    passes: u32,
+    on_progress: impl Fn(SecureEraseProgress) + Send + 'static,
+) -> Result<(), TrashError> {
+    tokio::task::spawn_blocking(move || secure_purge(items, passes, &on_progress)).await?
+}
+
+/// Securely erases the trash (`empty_trash` with overwrite passes, pinned items kept)
+///
+/// # Errors
+///
+/// Returns error if listing fails or any item could not be erased
+pub async fn secure_empty_trash(
+    pinned: Vec<String>,
+    passes: u32,
+    on_progress: impl Fn(SecureEraseProgress) + Send + 'static,
+) -> Result<(), TrashError> {
+    tokio::task::spawn_blocking(move || {
+        let items = unpinned(trash::os_limited::list()?, &pinned);
+        secure_purge(items, passes, &on_progress)
+    })
+    .await?
+}
+
+/// Drops pinned items (they are never bulk-deleted)
+fn unpinned(items: Vec<trash::TrashItem>, pinned: &[String]) -> Vec<trash::TrashItem> {
+    items
+        .into_iter()
+        .filter(|item| !config::is_pinned(pinned, item))
+        .collect()
+}
+
+fn secure_purge(
+    items: Vec<trash::TrashItem>,
+    passes: u32,
+    on_progress: &dyn Fn(SecureEraseProgress),
+) -> Result<(), TrashError> {
+    let passes = secure_erase::effective_passes(passes);
+    let paths: Vec<Option<PathBuf>> = items
+        .iter()
+        .map(trash_item_metadata::locate_in_trash)
+        .collect();
+    let bytes_total = paths
+        .iter()
+        .flatten()
+        .map(|path| secure_erase::content_size(path) * u64::from(passes))
+        .sum();
+
+    let single = items.len() == 1;
+    let mut bytes_done = 0u64;
+    let mut failed = Vec::new();
+
+    for (item, path) in items.into_iter().zip(paths) {
+        let current = item.name.to_string_lossy().into_owned();
+
+        // Never plain-delete an item that was meant to be erased
+        let Some(path) = &path else {
+            eprintln!("Cannot locate {current} in the trash; not deleting it unerased");
+            failed.push(ItemFailure {
+                item,
+                error: TrashError::NotFound { path: None },
+            });
+            continue;
+        };
+
+        let copy_on_write = secure_erase::copy_on_write_filesystem(path);
+        if let Some(fs_type) = &copy_on_write {
+            eprintln!(
+                "⚠️ {} is on {fs_type} (copy-on-write): overwriting may not erase old data",
+                path.display()
+            );
+        }
+
+        let mut report = |written: u64| {
+            bytes_done += written;
+            on_progress(SecureEraseProgress {
+                current: current.clone(),
+                bytes_done,
+                bytes_total,
+                copy_on_write: copy_on_write.clone(),
+            });
+        };
+        report(0);
+
+        if let Err(e) = secure_erase::overwrite(path, passes, &mut report) {
+            // Never unlink something we failed to overwrite
+            failed.push(ItemFailure {
+                item,
+                error: TrashError::io(path, &e),
+            });
+            continue;
+        }
+
+        if let Err(e) = trash::os_limited::purge_all([item.clone()]) {
+            failed.push(ItemFailure {
+                item,
+                error: TrashError::from(e),
+            });
+        }
+    }
+
+    if failed.is_empty() {
+        return Ok(());
+    }
+
+    eprintln!("Failed to securely erase {} items", failed.len());
+    if single && let Some(failure) = failed.pop() {
+        return Err(failure.error);
+    }
+    Err(TrashError::PartialFailure { failed })
 }
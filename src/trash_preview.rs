@@ -0,0 +1,330 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Backend: Quick-look preview of trash items
+//!
+//! Reads a small, bounded sample of an item directly from its trash bin
+//! (`<bin>/files/<name>`) so users can confirm what it is before restoring or purging.
+//! All filesystem access runs in `spawn_blocking`, like `trash_operations`.
+
+use crate::trash_item_metadata::EnrichedTrashItem;
+use std::io::Read;
+use std::os::unix::fs::PermissionsExt;
+use std::path::{Path, PathBuf};
+
+/// Maximum number of lines shown for text files
+const MAX_TEXT_LINES: usize = 12;
+/// Maximum number of bytes read from text files
+const MAX_TEXT_BYTES: u64 = 4096;
+/// Maximum number of children listed for directories
+const MAX_DIR_ENTRIES: usize = 10;
+
+/// Preview body, depending on the item type
+#[derive(Debug, Clone)]
+pub enum PreviewContent {
+    /// First lines of a text file
+    Text(String),
+    /// Image file, rendered from its path in the trash bin
+    Image(PathBuf),
+    /// Top-level children of a directory (name, is_dir) and total child count
+    Directory {
+        entries: Vec<(String, bool)>,
+        total: usize,
+    },
+    /// Binary or unreadable content (metadata only)
+    None,
+}
+
+/// Preview of a single trash item (content + file metadata)
+#[derive(Debug, Clone)]
+pub struct TrashPreview {
+    /// Trash item id (`.trashinfo` path), used to match the current selection
+    pub id: std::ffi::OsString,
+    pub content: PreviewContent,
+    /// Unix permissions in `ls -l` notation ("rwxr-xr-x")
+    pub permissions: Option<String>,
+    /// Last modification time ("2025-01-31 14:05")
+    pub modified: Option<String>,
+    /// Full original path (where the item will be restored)
+    pub original_path: PathBuf,
+}
+
+/// Loads the preview for an enriched trash item
+///
+/// Never fails: unreadable items produce `PreviewContent::None` and empty metadata.
+pub async fn load(enriched: EnrichedTrashItem) -> TrashPreview {
+    let id = enriched.item.id.clone();
+    let original_path = enriched.item.original_path();
+
+    tokio::task::spawn_blocking(move || build_preview(&enriched))
+        .await
+        .unwrap_or_else(|e| {
+            eprintln!("Failed to spawn preview task: {e}");
+            TrashPreview {
+                id,
+                content: PreviewContent::None,
+                permissions: None,
+                modified: None,
+                original_path,
+            }
+        })
+}
+
+fn build_preview(enriched: &EnrichedTrashItem) -> TrashPreview {
+    let metadata = enriched
+        .trash_path
+        .as_deref()
+        .and_then(|path| std::fs::symlink_metadata(path).ok());
+
+    let content = match (&enriched.trash_path, &metadata) {
+        (Some(path), Some(meta)) if meta.is_dir() => read_directory(path),
+        (Some(path), Some(meta)) if meta.is_file() => {
+            if enriched.mime.type_() == mime_guess::mime::IMAGE {
+                PreviewContent::Image(path.clone())
+            } else if is_text_mime(&enriched.mime) {
+                read_text(path)
+            } else {
+                PreviewContent::None
+            }
+        }
+        _ => PreviewContent::None,
+    };
+
+    TrashPreview {
+        id: enriched.item.id.clone(),
+        content,
+        permissions: metadata
+            .as_ref()
+            .map(|meta| format_permissions(meta.permissions().mode())),
+        modified: metadata
+            .as_ref()
+            .and_then(|meta| meta.modified().ok())
+            .map(|time| {
+                chrono::DateTime::<chrono::Local>::from(time)
+                    .format("%Y-%m-%d %H:%M")
+                    .to_string()
+            }),
+        original_path: enriched.item.original_path(),
+    }
+}
+
+/// Text-like MIME types (text/*, plus common structured text formats)
+fn is_text_mime(mime: &mime_guess::Mime) -> bool {
+    mime.type_() == mime_guess::mime::TEXT
+        || matches!(
+            mime.essence_str(),
+            "application/json"
+                | "application/xml"
+                | "application/javascript"
+                | "application/x-shellscript"
+                | "application/toml"
+                | "application/x-yaml"
+        )
+}
+
+fn read_text(path: &Path) -> PreviewContent {
+    let Ok(file) = std::fs::File::open(path) else {
+        return PreviewContent::None;
+    };
+
+    let mut buffer = Vec::new();
+    if file.take(MAX_TEXT_BYTES).read_to_end(&mut buffer).is_err() {
+        return PreviewContent::None;
+    }
+
+    let text = String::from_utf8_lossy(&buffer)
+        .lines()
+        .take(MAX_TEXT_LINES)
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    PreviewContent::Text(text)
+}
+
+fn read_directory(path: &Path) -> PreviewContent {
+    let Ok(read_dir) = std::fs::read_dir(path) else {
+        return PreviewContent::None;
+    };
+
+    let mut entries: Vec<(String, bool)> = read_dir
+        .filter_map(Result::ok)
+        .map(|entry| {
+            let is_dir = entry.file_type().is_ok_and(|kind| kind.is_dir());
+            (entry.file_name().to_string_lossy().into_owned(), is_dir)
+        })
+        .collect();
+    let total = entries.len();
+
+    // Same ordering as the items list: folders first, then alphabetical
+    entries.sort_by(|a, b| {
+        b.1.cmp(&a.1)
+            .then_with(|| a.0.to_lowercase().cmp(&b.0.to_lowercase()))
+    });
+    entries.truncate(MAX_DIR_ENTRIES);
+
+    PreviewContent::Directory { entries, total }
+}
+
+/// Formats a Unix mode as `ls -l` style permissions ("rwxr-xr-x")
+fn format_permissions(mode: u32) -> String {
+    const FLAGS: [(u32, char); 9] = [
+        (0o400, 'r'),
+        (0o200, 'w'),
+        (0o100, 'x'),
+        (0o040, 'r'),
+        (0o020, 'w'),
+        (0o010, 'x'),
+        (0o004, 'r'),
+        (0o002, 'w'),
+        (0o001, 'x'),
+    ];
+
+    FLAGS
+        .iter()
+        .map(|&(bit, flag)| if mode & bit == 0 { '-' } else { flag })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    /// Item stored at `trash_path`, with the MIME type `from_trash_item` would detect
+    fn enriched(trash_path: &Path, mime: &str) -> EnrichedTrashItem {
+        EnrichedTrashItem {
+            item: trash::TrashItem {
+                id: "/home/u/.local/share/Trash/info/item.trashinfo".into(),
+                name: "item".into(),
+                original_parent: PathBuf::from("/home/u/Documents"),
+                time_deleted: 0,
+            },
+            trash_path: Some(trash_path.to_path_buf()),
+            size_bytes: None,
+            entry_count: None,
+            mime: mime.parse().unwrap(),
+            is_dir: trash_path.is_dir(),
+        }
+    }
+
+    #[test]
+    fn text_file_shows_first_lines() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("notes.txt");
+        let lines: Vec<String> = (1..=20).map(|n| format!("line {n}")).collect();
+        fs::write(&path, lines.join("\n")).unwrap();
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o640)).unwrap();
+
+        let preview = build_preview(&enriched(&path, "text/plain"));
+
+        let PreviewContent::Text(text) = preview.content else {
+            panic!("expected text, got {:?}", preview.content);
+        };
+        assert_eq!(text, lines[..MAX_TEXT_LINES].join("\n"));
+        assert_eq!(preview.permissions.as_deref(), Some("rw-r-----"));
+        assert!(preview.modified.is_some());
+        assert_eq!(
+            preview.original_path,
+            PathBuf::from("/home/u/Documents/item")
+        );
+    }
+
+    #[test]
+    fn structured_text_is_previewed() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("data.json");
+        fs::write(&path, "{\"a\": 1}\n").unwrap();
+
+        let preview = build_preview(&enriched(&path, "application/json"));
+
+        assert!(matches!(preview.content, PreviewContent::Text(text) if text == "{\"a\": 1}"));
+    }
+
+    #[test]
+    fn large_text_file_reads_only_the_first_bytes() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("huge.log");
+        // One long line, so the byte cap applies before the line cap
+        fs::write(&path, "a".repeat(1024 * 1024)).unwrap();
+
+        let preview = build_preview(&enriched(&path, "text/plain"));
+
+        let PreviewContent::Text(text) = preview.content else {
+            panic!("expected text, got {:?}", preview.content);
+        };
+        assert_eq!(text.len() as u64, MAX_TEXT_BYTES);
+    }
+
+    #[test]
+    fn text_cut_inside_a_character_is_still_shown() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("accents.txt");
+        // "é" is two bytes: the byte cap lands between them
+        let prefix = "a".repeat(usize::try_from(MAX_TEXT_BYTES - 1).unwrap());
+        fs::write(&path, format!("{prefix}é")).unwrap();
+
+        let preview = build_preview(&enriched(&path, "text/plain"));
+
+        let PreviewContent::Text(text) = preview.content else {
+            panic!("expected text, got {:?}", preview.content);
+        };
+        assert!(text.starts_with(&prefix));
+        assert!(text.ends_with(char::REPLACEMENT_CHARACTER));
+    }
+
+    #[test]
+    fn binary_file_shows_metadata_only() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("program");
+        fs::write(&path, [0x7f, b'E', b'L', b'F', 0, 0xff, 0xfe]).unwrap();
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o755)).unwrap();
+
+        let preview = build_preview(&enriched(&path, "application/octet-stream"));
+
+        assert!(matches!(preview.content, PreviewContent::None));
+        assert_eq!(preview.permissions.as_deref(), Some("rwxr-xr-x"));
+        assert!(preview.modified.is_some());
+    }
+
+    #[test]
+    fn image_is_rendered_from_the_trash_path() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("photo.png");
+        fs::write(&path, b"not really a png").unwrap();
+
+        let preview = build_preview(&enriched(&path, "image/png"));
+
+        assert!(matches!(preview.content, PreviewContent::Image(image) if image == path));
+    }
+
+    #[test]
+    fn directory_lists_folders_first_and_caps_entries() {
+        let dir = tempfile::tempdir().unwrap();
+        let folder = dir.path().join("project");
+        fs::create_dir(&folder).unwrap();
+        fs::create_dir(folder.join("src")).unwrap();
+        for n in 0..12 {
+            fs::write(folder.join(format!("File{n:02}.txt")), "").unwrap();
+        }
+
+        let preview = build_preview(&enriched(&folder, "inode/directory"));
+
+        let PreviewContent::Directory { entries, total } = preview.content else {
+            panic!("expected directory, got {:?}", preview.content);
+        };
+        assert_eq!(total, 13);
+        assert_eq!(entries.len(), MAX_DIR_ENTRIES);
+        assert_eq!(entries[0], ("src".to_string(), true));
+        assert_eq!(entries[1], ("File00.txt".to_string(), false));
+    }
+
+    #[test]
+    fn missing_item_has_no_content_or_metadata() {
+        let dir = tempfile::tempdir().unwrap();
+
+        let preview = build_preview(&enriched(&dir.path().join("gone.txt"), "text/plain"));
+
+        assert!(matches!(preview.content, PreviewContent::None));
+        assert_eq!(preview.permissions, None);
+        assert_eq!(preview.modified, None);
+    }
+}
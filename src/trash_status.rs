@@ -4,13 +4,16 @@
 //!
 //! Replicated from cosmic-files/src/tab.rs using trash-rs
 
+use serde::Serialize;
 use std::path::PathBuf;
 
 /// Trash status (Backend, no UI dependencies)
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct TrashStatus {
     pub is_empty: bool,
     pub item_count: usize,
+    /// Deletion time of the oldest item (Unix seconds)
+    pub oldest_deleted: Option<i64>,
 }
 
 impl TrashStatus {
@@ -20,17 +23,16 @@ impl TrashStatus {
     #[must_use]
     pub fn check() -> Self {
         let is_empty = trash::os_limited::is_empty().unwrap_or(true);
-        let item_count = if is_empty {
-            0
+        let entries = if is_empty {
+            Vec::new()
         } else {
-            trash::os_limited::list()
-                .map(|entries| entries.len())
-                .unwrap_or(0)
+            trash::os_limited::list().unwrap_or_default()
         };
 
         Self {
             is_empty,
-            item_count,
+            item_count: entries.len(),
+            oldest_deleted: entries.iter().map(|entry| entry.time_deleted).min(),
         }
     }
 
@@ -57,7 +59,6 @@ impl TrashStatus {
     }
 
     /// Returns default trash directory path on Linux
-    #[allow(dead_code)] // Will be used in future phases
     #[must_use]
     pub fn trash_dir() -> PathBuf {
         let home = std::env::var("HOME").unwrap_or_default();
@@ -70,6 +71,7 @@ impl Default for TrashStatus {
         Self {
             is_empty: true,
             item_count: 0,
+            oldest_deleted: None,
         }
     }
 }
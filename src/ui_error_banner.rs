@@ -0,0 +1,141 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! UI Module: Dismissible error banner
+//!
+//! Lists recent operation errors at the top of the popup, with retry and dismiss actions
+
+use crate::app::Message;
+use crate::error::TrashError;
+use crate::fl;
+use crate::operation_error::OperationError;
+use crate::trash_item_metadata::EnrichedTrashItem;
+use cosmic::iced::Length;
+use cosmic::prelude::*;
+use cosmic::widget::{self, tooltip};
+
+/// Renders the banner, or nothing when there are no errors
+#[must_use]
+pub fn view(errors: &[OperationError]) -> Option<Element<'_, Message>> {
+    if errors.is_empty() {
+        return None;
+    }
+
+    let mut rows = widget::column().spacing(8);
+
+    // Newest first
+    for (index, error) in errors.iter().enumerate().rev() {
+        let mut actions = widget::row().spacing(4);
+        if let TrashError::Conflict { path } = &error.error {
+            actions = actions.push(tooltip(
+                widget::button::icon(widget::icon::from_name("folder-open-symbolic").size(16))
+                    .on_press(Message::RevealOriginal(path.clone()))
+                    .name(fl!("a11y-show-conflict", path = path.display().to_string())),
+                widget::text(fl!("show-conflicting-file")),
+                tooltip::Position::Bottom,
+            ));
+        }
+        if error.is_retryable() {
+            actions = actions.push(tooltip(
+                widget::button::icon(widget::icon::from_name("view-refresh-symbolic").size(16))
+                    .on_press(Message::RetryOperation(index))
+                    .name(fl!("a11y-retry", operation = error.title())),
+                widget::text(fl!("retry")),
+                tooltip::Position::Bottom,
+            ));
+        }
+        actions = actions.push(tooltip(
+            widget::button::icon(widget::icon::from_name("window-close-symbolic").size(16))
+                .on_press(Message::DismissError(index))
+                .name(fl!("a11y-dismiss-error", operation = error.title())),
+            widget::text(fl!("dismiss")),
+            tooltip::Position::Bottom,
+        ));
+
+        let mut row = widget::column().push(
+            widget::row()
+                .push(widget::icon::from_name("dialog-error-symbolic").size(16))
+                .push(
+                    widget::column()
+                        .push(widget::text::body(error.title()))
+                        .push(widget::text::caption(error.error.message()))
+                        .spacing(2)
+                        .width(Length::Fill),
+                )
+                .push(actions)
+                .spacing(8)
+                .align_y(cosmic::iced::Alignment::Center),
+        );
+
+        // Partial failure: one row per item that could not be handled
+        if matches!(error.error, TrashError::PartialFailure { .. }) {
+            for (item_index, enriched) in error.items.iter().enumerate() {
+                row = row.push(failed_item_row(error, index, item_index, enriched));
+            }
+        }
+
+        rows = rows.push(row.spacing(4));
+    }
+
+    if errors.len() > 1 {
+        rows =
+            rows.push(widget::row().push(widget::horizontal_space()).push(
+                widget::button::text(fl!("dismiss-all")).on_press(Message::DismissAllErrors),
+            ));
+    }
+
+    Some(
+        widget::container(rows)
+            .padding(12)
+            .width(Length::Fill)
+            .class(cosmic::theme::Container::Card)
+            .into(),
+    )
+}
+
+/// Failed item inside a partial failure: name, reason, retry and open location
+fn failed_item_row<'a>(
+    error: &'a OperationError,
+    index: usize,
+    item_index: usize,
+    enriched: &'a EnrichedTrashItem,
+) -> Element<'a, Message> {
+    let reason = error
+        .item_reason(enriched)
+        .map(TrashError::message)
+        .unwrap_or_default();
+
+    // The stuck file lives in the trash bin; fall back to where it came from
+    let location = enriched
+        .trash_path
+        .clone()
+        .unwrap_or_else(|| enriched.item.original_path());
+
+    let name = enriched.item.name.to_string_lossy().to_string();
+
+    widget::row()
+        .push(
+            widget::column()
+                .push(widget::text::body(name.clone()))
+                .push(widget::text::caption(reason))
+                .spacing(2)
+                .width(Length::Fill),
+        )
+        .push(tooltip(
+            widget::button::icon(widget::icon::from_name("folder-open-symbolic").size(16))
+                .on_press(Message::RevealOriginal(location))
+                .name(fl!("a11y-open-failed-location", name = name.clone())),
+            widget::text(fl!("open-location")),
+            tooltip::Position::Bottom,
+        ))
+        .push(tooltip(
+            widget::button::icon(widget::icon::from_name("view-refresh-symbolic").size(16))
+                .on_press(Message::RetryFailedItem(index, item_index))
+                .name(fl!("a11y-retry-failed-item", name = name)),
+            widget::text(fl!("retry")),
+            tooltip::Position::Bottom,
+        ))
+        .spacing(4)
+        .padding([0, 0, 0, 24])
+        .align_y(cosmic::iced::Alignment::Center)
+        .into()
+}
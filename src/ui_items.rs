@@ -4,51 +4,123 @@
 //!
 //! cosmic-files style: large icons, name+size column, centered actions
 
-use crate::app::Message;
+use crate::app::{Confirmation, Message};
+use crate::config;
+use crate::fl;
 use crate::mime_icon::mime_icon;
+use crate::secure_erase;
+use crate::size_format::SizeUnits;
 use crate::trash_item_metadata::EnrichedTrashItem;
-use cosmic::iced::Length;
+use crate::trash_preview::TrashPreview;
+use crate::ui_preview;
+use cosmic::iced::widget::scrollable::{RelativeOffset, snap_to};
+use cosmic::iced::{Border, Length};
 use cosmic::prelude::*;
 use cosmic::widget::{self, icon, scrollable, tooltip};
+use std::ffi::OsString;
+use std::sync::LazyLock;
+
+/// Width of the actions column (header + rows)
+const ACTIONS_WIDTH: f32 = 152.0;
+
+/// Items scrollable (keyboard focus scrolls it)
+static LIST_SCROLLABLE: LazyLock<widget::Id> = LazyLock::new(|| widget::Id::new("trash-items"));
+
+/// Scrolls the list so the `index`-th of `count` visible rows is in view
+#[must_use]
+#[allow(clippy::cast_precision_loss)]
+pub fn scroll_to(index: usize, count: usize) -> Task<cosmic::Action<Message>> {
+    let y = if count > 1 {
+        index as f32 / (count - 1) as f32
+    } else {
+        0.0
+    };
+    snap_to(LIST_SCROLLABLE.clone(), RelativeOffset { x: 0.0, y })
+}
 
 /// Renders scrollable list of trash items
 ///
 /// NO title/divider here - those are in ui_popup.rs
+/// The selected item (by trash id) is expanded with an inline preview; only
+/// items matching `query` are listed. The keyboard-focused row gets an accent
+/// outline, marked rows a check mark.
 #[must_use]
-pub fn view(items: &[EnrichedTrashItem], sort_ascending: bool) -> Element<'_, Message> {
+#[allow(clippy::too_many_arguments)]
+pub fn view<'a>(
+    items: &'a [EnrichedTrashItem],
+    sort_ascending: bool,
+    selected: Option<&OsString>,
+    focused: Option<&OsString>,
+    marked: &[OsString],
+    query: &str,
+    preview: Option<&'a TrashPreview>,
+    pinned: &[String],
+    units: SizeUnits,
+    compact: bool,
+) -> Element<'a, Message> {
     if items.is_empty() {
         return widget::column().into();
     }
+    let items: Vec<&EnrichedTrashItem> = items.iter().filter(|item| item.matches(query)).collect();
 
     // Triangle rotation based on sort order (cosmic-files pattern)
-    let sort_icon = if sort_ascending {
-        "pan-up-symbolic" // ▲ Ascending A-Z
+    let (sort_icon, sort_name) = if sort_ascending {
+        ("pan-up-symbolic", fl!("a11y-sort-ascending")) // ▲ Ascending A-Z
     } else {
-        "pan-down-symbolic" // ▼ Descending Z-A
+        ("pan-down-symbolic", fl!("a11y-sort-descending")) // ▼ Descending Z-A
     };
 
     let header = widget::row()
         .push(
             widget::button::custom(
                 widget::row()
-                    .push(widget::text::heading("Files"))
+                    .push(widget::text::heading(fl!("list-files")))
                     .push(widget::icon::from_name(sort_icon).size(16))
                     .spacing(4)
                     .align_y(cosmic::iced::Alignment::End),
             )
             .on_press(Message::ToggleSortOrder)
+            .name(sort_name)
             .class(cosmic::theme::Button::MenuRoot),
         )
         .push(widget::horizontal_space())
-        .push(widget::text::heading("Actions").width(Length::Fixed(80.0)))
+        .push(widget::text::heading(fl!("list-actions")).width(Length::Fixed(ACTIONS_WIDTH)))
         .spacing(12)
         .padding([0, 12])
         .align_y(cosmic::iced::Alignment::Center);
 
     // Items with dividers
     let mut item_list = Vec::new();
-    for (i, item) in items.iter().enumerate() {
-        item_list.push(item_row(item));
+    if items.is_empty() {
+        item_list.push(
+            widget::container(widget::text::caption(fl!("search-no-match")))
+                .padding([8, 12])
+                .into(),
+        );
+    }
+    for (i, item) in items.iter().copied().enumerate() {
+        let is_selected = selected == Some(&item.item.id);
+        let is_pinned = config::is_pinned(pinned, &item.item);
+        let row = item_row(
+            item,
+            is_selected,
+            is_pinned,
+            marked.contains(&item.item.id),
+            units,
+            compact,
+        );
+        item_list.push(if focused == Some(&item.item.id) {
+            focus_ring(row)
+        } else {
+            row
+        });
+        if is_selected {
+            // Only show the preview once it matches the selection (avoids stale content)
+            item_list.push(ui_preview::view(
+                preview.filter(|preview| preview.id == item.item.id),
+            ));
+            item_list.push(selected_actions_row(item, is_pinned));
+        }
         if i < items.len() - 1 {
             item_list.push(widget::divider::horizontal::default().into());
         }
@@ -60,6 +132,7 @@ pub fn view(items: &[EnrichedTrashItem], sort_ascending: bool) -> Element<'_, Me
         .push(widget::divider::horizontal::default())
         .push(
             scrollable(widget::column::with_children(item_list))
+                .id(LIST_SCROLLABLE.clone())
                 .height(Length::Fixed(250.0)) // Max 250px, then scroll
                 .width(Length::Fill),
         )
@@ -67,43 +140,145 @@ pub fn view(items: &[EnrichedTrashItem], sort_ascending: bool) -> Element<'_, Me
 }
 
 /// Single item: Icon (32px) | Name+Size column | Actions
-fn item_row(enriched: &EnrichedTrashItem) -> Element<'_, Message> {
-    // Icon: 32px (smaller than before)
-    let icon_handle = mime_icon(enriched.mime.clone(), 32);
-    let icon_widget = icon::icon(icon_handle).size(32);
+///
+/// Clicking the icon/name area toggles the inline preview.
+fn item_row(
+    enriched: &EnrichedTrashItem,
+    is_selected: bool,
+    is_pinned: bool,
+    is_marked: bool,
+    units: SizeUnits,
+    compact: bool,
+) -> Element<'_, Message> {
+    // Icon: 32px (24px in compact density)
+    let (icon_size, row_padding) = if compact { (24, 4) } else { (32, 8) };
+    let icon_handle = mime_icon(enriched.mime.clone(), icon_size);
+    let icon_widget = icon::icon(icon_handle).size(icon_size);
+    let name = enriched.item.name.to_string_lossy().to_string();
+    let size = enriched.size_display(units);
 
-    // Text column: Name + Size
+    // Text column: (check mark +) Name (+ pin marker) + Size
+    let mut name_row = widget::row()
+        .spacing(4)
+        .align_y(cosmic::iced::Alignment::Center);
+    if is_marked {
+        name_row = name_row.push(widget::icon::from_name("object-select-symbolic").size(12));
+    }
+    name_row = name_row.push(widget::text::body(name.clone()));
+    if is_pinned {
+        name_row = name_row.push(widget::icon::from_name("view-pin-symbolic").size(12));
+    }
     let text_column = widget::column()
-        .push(widget::text::body(
-            enriched.item.name.to_string_lossy().to_string(),
-        ))
-        .push(widget::text::caption(&enriched.size_display))
+        .push(name_row)
+        .push(widget::text::caption(size.clone()))
         .spacing(4)
         .width(Length::Fill);
 
-    // Actions (centered)
+    // Actions (centered); accessible names include the item name
     let actions = widget::row()
+        .push(tooltip(
+            widget::button::icon(widget::icon::from_name("document-open-symbolic").size(16))
+                .on_press(Message::OpenItem(enriched.clone()))
+                .name(fl!("a11y-open-item", name = name.clone())),
+            widget::text(fl!("action-open")),
+            tooltip::Position::Bottom,
+        ))
+        .push(tooltip(
+            widget::button::icon(widget::icon::from_name("folder-open-symbolic").size(16))
+                .on_press(Message::RevealOriginal(enriched.item.original_path()))
+                .name(fl!("a11y-reveal-item", name = name.clone())),
+            widget::text(fl!("action-reveal")),
+            tooltip::Position::Bottom,
+        ))
         .push(tooltip(
             widget::button::icon(widget::icon::from_name("edit-undo-symbolic").size(16))
-                .on_press(Message::RestoreItem(enriched.clone())),
-            "Restore",
+                .on_press(Message::RestoreItem(enriched.clone()))
+                .name(fl!("a11y-restore-item", name = name.clone())),
+            widget::text(fl!("action-restore")),
             tooltip::Position::Bottom,
         ))
         .push(tooltip(
             widget::button::icon(widget::icon::from_name("edit-delete-symbolic").size(16))
-                .on_press(Message::DeleteItem(enriched.clone())),
-            "Delete",
+                .on_press(Message::Confirm(Confirmation::Delete {
+                    item: enriched.clone(),
+                    secure: false,
+                }))
+                .name(fl!("a11y-delete-item", name = name.clone())),
+            widget::text(fl!("action-delete")),
             tooltip::Position::Bottom,
         ))
         .spacing(4)
-        .width(Length::Fixed(80.0));
+        .width(Length::Fixed(ACTIONS_WIDTH));
+
+    // Selectable area: icon + name/size
+    let select_area = widget::button::custom(
+        widget::row()
+            .push(icon_widget)
+            .push(text_column)
+            .spacing(12)
+            .align_y(cosmic::iced::Alignment::Center),
+    )
+    .on_press(Message::SelectItem(enriched.clone()))
+    .name(fl!("a11y-item", name = name.clone(), size = size))
+    .description(fl!(
+        "a11y-item-description",
+        location = enriched.item.original_parent.display().to_string()
+    ))
+    .selected(is_selected)
+    .class(cosmic::theme::Button::MenuItem)
+    .padding(0)
+    .width(Length::Fill);
 
     widget::row()
-        .push(icon_widget)
-        .push(text_column)
+        .push(select_area)
         .push(actions)
         .spacing(12)
-        .padding([8, 12])
+        .padding([row_padding, 12])
         .align_y(cosmic::iced::Alignment::Center)
         .into()
 }
+
+/// Accent outline around the keyboard-focused row
+fn focus_ring(row: Element<'_, Message>) -> Element<'_, Message> {
+    widget::container(row)
+        .class(cosmic::theme::Container::custom(|theme| {
+            let cosmic = theme.cosmic();
+            widget::container::Style {
+                border: Border {
+                    color: cosmic.accent_color().into(),
+                    width: 2.0,
+                    radius: cosmic.corner_radii.radius_s.into(),
+                },
+                ..Default::default()
+            }
+        }))
+        .into()
+}
+
+/// Below the preview: pin toggle and secure delete for the selected item
+fn selected_actions_row(enriched: &EnrichedTrashItem, is_pinned: bool) -> Element<'_, Message> {
+    let pin_label = if is_pinned { fl!("unpin") } else { fl!("pin") };
+
+    widget::row()
+        .push(widget::horizontal_space())
+        .push(tooltip(
+            widget::button::standard(pin_label)
+                .leading_icon(widget::icon::from_name("view-pin-symbolic").size(16))
+                .on_press(Message::TogglePin(enriched.clone())),
+            widget::text(fl!("pin-description")),
+            tooltip::Position::Top,
+        ))
+        .push(tooltip(
+            widget::button::destructive(fl!("secure-delete")).on_press(Message::Confirm(
+                Confirmation::Delete {
+                    item: enriched.clone(),
+                    secure: true,
+                },
+            )),
+            widget::text(secure_erase::limitations()),
+            tooltip::Position::Top,
+        ))
+        .spacing(8)
+        .padding([0, 12, 8, 12])
+        .into()
+}
@@ -2,56 +2,277 @@
 
 //! Frontend: Panel icon (reactive to trash status)
 //!
-//! Adaptive UI: uses colored icon in Dock and symbolic icon in Panel
+//! Adaptive UI: uses colored icon in Dock and symbolic icon in Panel.
+//! Optionally overlays an item count badge or a fill level bar
+//! (`Config::panel_indicator`). The tooltip summarizes the trash: item count,
+//! total size, oldest item and next automatic cleanup. Middle/right click run
+//! `Config::middle_click` / `right_click`; scrolling cycles the tooltip through
+//! recently trashed items.
 
 use crate::app::Message;
+use crate::config::{Config, PanelAction, PanelIndicator};
+use crate::size_format::{self, SizeUnits};
+use crate::trash_item_metadata::EnrichedTrashItem;
 use crate::trash_status::TrashStatus;
+use crate::{fl, i18n};
 use cosmic::applet::PanelType;
-use cosmic::iced::Length;
+use cosmic::iced::mouse::ScrollDelta;
+use cosmic::iced::widget::{Stack, mouse_area};
+use cosmic::iced::{Alignment, Length};
 use cosmic::prelude::*;
 use cosmic::widget;
 
+/// Counts above this are shown as `99+`
+const BADGE_MAX: usize = 99;
+
 #[must_use]
-pub fn view<'a>(trash_status: &TrashStatus, core: &cosmic::Core) -> Element<'a, Message> {
-    match &core.applet.panel_type {
+pub fn view<'a>(
+    trash_status: &TrashStatus,
+    trash_size: u64,
+    next_cleanup: Option<i64>,
+    recent: &[&EnrichedTrashItem],
+    recent_index: Option<usize>,
+    config: &Config,
+    core: &cosmic::Core,
+) -> Element<'a, Message> {
+    // Screen readers announce the item count along with the button
+    let accessible_name = fl!("a11y-panel-status", count = trash_status.item_count);
+    let mut summary = summary(trash_status, trash_size, next_cleanup, config.size_units);
+    if let Some((index, enriched)) =
+        recent_index.and_then(|index| Some((index, *recent.get(index)?)))
+    {
+        summary.push('\n');
+        summary.push_str(&recent_line(index, recent.len(), enriched));
+    }
+    let fill = fill_level(trash_size, config);
+    let indicator = indicator(trash_status, fill, config.panel_indicator);
+
+    let button = match &core.applet.panel_type {
         PanelType::Dock => {
             // Dock: large colored icon with proper button shape
             let icon_handle = widget::icon::from_name(trash_status.icon_name_dock()).handle();
-            let suggested = core.applet.suggested_size(false); // false = not symbolic
-            let (major_padding, minor_padding) = core.applet.suggested_padding(false);
-
-            // Calculate padding based on orientation
-            let (horizontal_padding, vertical_padding) = if core.applet.is_horizontal() {
-                (major_padding, minor_padding)
-            } else {
-                (minor_padding, major_padding)
-            };
-
-            // Use layer_container pattern from cosmic-panel-button for correct hover shape
-            let button = widget::button::custom(
-                widget::layer_container(
-                    widget::icon(icon_handle)
-                        .width(Length::Fixed(suggested.0 as f32))
-                        .height(Length::Fixed(suggested.1 as f32)),
-                )
-                .center(Length::Fill),
-            )
-            .width(Length::Fixed((suggested.0 + 2 * horizontal_padding) as f32))
-            .height(Length::Fixed((suggested.1 + 2 * vertical_padding) as f32))
-            .on_press_down(Message::TogglePopup)
-            .class(cosmic::theme::Button::AppletIcon);
-
-            // Wrap with tooltip
-            core.applet
-                .applet_tooltip(button, "Trash", false, Message::Surface, None)
-                .into()
+            icon_button(core, icon_handle, false, indicator, accessible_name, fill)
+                .on_press_down(Message::TogglePopup)
         }
-        PanelType::Panel | PanelType::Other(_) => {
+        PanelType::Panel | PanelType::Other(_) => match indicator {
             // Panel: small symbolic icon (icon_button adds -symbolic automatically)
-            core.applet
+            None => core
+                .applet
                 .icon_button(trash_status.icon_name_panel())
                 .on_press(Message::TogglePopup)
-                .into()
+                .name(accessible_name),
+            // Overlays need the icon inside a custom button
+            Some(indicator) => {
+                let icon_handle = widget::icon::from_name(trash_status.icon_name_panel())
+                    .symbolic(true)
+                    .handle();
+                icon_button(
+                    core,
+                    icon_handle,
+                    true,
+                    Some(indicator),
+                    accessible_name,
+                    fill,
+                )
+                .on_press(Message::TogglePopup)
+            }
+        },
+    };
+
+    // Wrap with tooltip
+    core.applet
+        .applet_tooltip(
+            mouse_actions(button.into(), config),
+            summary,
+            false,
+            Message::Surface,
+            None,
+        )
+        .into()
+}
+
+/// Middle/right click and scroll handlers around the button (left click stays with it)
+fn mouse_actions<'a>(button: Element<'a, Message>, config: &Config) -> Element<'a, Message> {
+    let mut area = mouse_area(button).on_exit(Message::PanelExit);
+    if config.middle_click != PanelAction::Nothing {
+        area = area.on_middle_press(Message::PanelAction(config.middle_click));
+    }
+    if config.right_click != PanelAction::Nothing {
+        area = area.on_right_press(Message::PanelAction(config.right_click));
+    }
+    if config.scroll_recent {
+        // Down: older item, up: newer
+        area = area.on_scroll(|delta| {
+            let (ScrollDelta::Lines { y, .. } | ScrollDelta::Pixels { y, .. }) = delta;
+            Message::CycleRecent(if y < 0.0 { 1 } else { -1 })
+        });
+    }
+    area.into()
+}
+
+/// Tooltip line for the `index`-th of `count` recent items
+fn recent_line(index: usize, count: usize, enriched: &EnrichedTrashItem) -> String {
+    let position = index + 1;
+    fl!(
+        "tooltip-recent",
+        position = position,
+        count = count,
+        name = enriched.item.name.to_string_lossy().to_string(),
+        age = i18n::format_duration(chrono::Utc::now().timestamp() - enriched.item.time_deleted)
+    )
+}
+
+/// Tooltip text: count and size, oldest item, next cleanup (one per line)
+fn summary(
+    trash_status: &TrashStatus,
+    trash_size: u64,
+    next_cleanup: Option<i64>,
+    units: SizeUnits,
+) -> String {
+    if trash_status.is_empty {
+        return fl!("trash-is-empty");
+    }
+
+    let now = chrono::Utc::now().timestamp();
+    let mut lines = vec![
+        fl!("trash"),
+        fl!(
+            "tooltip-contents",
+            count = trash_status.item_count,
+            size = size_format::format(trash_size, units)
+        ),
+    ];
+    if let Some(oldest) = trash_status.oldest_deleted {
+        lines.push(fl!(
+            "tooltip-oldest",
+            age = i18n::format_duration(now - oldest)
+        ));
+    }
+    if let Some(next) = next_cleanup {
+        lines.push(if next > now {
+            fl!(
+                "tooltip-next-cleanup",
+                duration = i18n::format_duration(next - now)
+            )
+        } else {
+            fl!("tooltip-next-cleanup-soon")
+        });
+    }
+
+    lines.join("\n")
+}
+
+/// Applet-sized icon button with an optional overlay on the icon
+///
+/// Uses the layer_container pattern from cosmic-panel-button for the correct
+/// hover shape; the caller adds the press handler.
+fn icon_button<'a>(
+    core: &cosmic::Core,
+    icon_handle: widget::icon::Handle,
+    symbolic: bool,
+    indicator: Option<Element<'a, Message>>,
+    accessible_name: String,
+    fill: Option<f32>,
+) -> widget::Button<'a, Message> {
+    let suggested = core.applet.suggested_size(symbolic);
+    let (major_padding, minor_padding) = core.applet.suggested_padding(symbolic);
+
+    // Calculate padding based on orientation
+    let (horizontal_padding, vertical_padding) = if core.applet.is_horizontal() {
+        (major_padding, minor_padding)
+    } else {
+        (minor_padding, major_padding)
+    };
+
+    let icon: Element<'a, Message> = widget::icon(icon_handle)
+        .width(Length::Fixed(suggested.0 as f32))
+        .height(Length::Fixed(suggested.1 as f32))
+        .into();
+    let content: Element<'a, Message> = match indicator {
+        Some(indicator) => Stack::new()
+            .push(icon)
+            .push(indicator)
+            .width(Length::Fixed(suggested.0 as f32))
+            .height(Length::Fixed(suggested.1 as f32))
+            .into(),
+        None => icon,
+    };
+
+    let mut button = widget::button::custom(widget::layer_container(content).center(Length::Fill))
+        .width(Length::Fixed((suggested.0 + 2 * horizontal_padding) as f32))
+        .height(Length::Fixed((suggested.1 + 2 * vertical_padding) as f32))
+        .name(accessible_name)
+        .class(cosmic::theme::Button::AppletIcon);
+    if let Some(fill) = fill {
+        button = button.description(fl!(
+            "a11y-panel-fill",
+            percent = i18n::format_decimal(f64::from(fill) * 100.0, 0)
+        ));
+    }
+    button
+}
+
+/// Overlay for `indicator` (None when there is nothing to show)
+fn indicator<'a>(
+    trash_status: &TrashStatus,
+    fill: Option<f32>,
+    indicator: PanelIndicator,
+) -> Option<Element<'a, Message>> {
+    match indicator {
+        PanelIndicator::None => None,
+        PanelIndicator::ItemCount => {
+            (!trash_status.is_empty).then(|| count_badge(trash_status.item_count))
         }
+        PanelIndicator::FillLevel => fill.map(fill_bar),
     }
 }
+
+/// Fraction of `Config::quota_mb` used by the trash (capped at 1.0)
+///
+/// None unless the fill level indicator is enabled with a quota.
+#[allow(clippy::cast_precision_loss)]
+fn fill_level(trash_size: u64, config: &Config) -> Option<f32> {
+    let quota = config.quota_mb.saturating_mul(1024 * 1024);
+    (config.panel_indicator == PanelIndicator::FillLevel && quota > 0)
+        .then(|| (trash_size as f64 / quota as f64).min(1.0) as f32)
+}
+
+/// Accent pill with the item count in the top-right corner
+fn count_badge<'a>(count: usize) -> Element<'a, Message> {
+    let label = if count > BADGE_MAX {
+        format!("{}+", i18n::format_count(BADGE_MAX as u64))
+    } else {
+        i18n::format_count(count as u64)
+    };
+
+    let badge = widget::container(widget::text::caption(label).size(9))
+        .padding([0, 3])
+        .class(cosmic::theme::Container::custom(|theme| {
+            let cosmic = theme.cosmic();
+            widget::container::Style {
+                background: Some(cosmic.accent_color().into()),
+                text_color: Some(cosmic.accent.on.into()),
+                border: cosmic::iced::Border {
+                    radius: cosmic.corner_radii.radius_xl.into(),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        }));
+
+    widget::container(badge)
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .align_x(Alignment::End)
+        .align_y(Alignment::Start)
+        .into()
+}
+
+/// Thin bar along the bottom edge of the icon
+fn fill_bar<'a>(fill: f32) -> Element<'a, Message> {
+    widget::container(widget::progress_bar(0.0..=1.0, fill).height(3))
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .align_y(Alignment::End)
+        .into()
+}
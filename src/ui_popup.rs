@@ -2,42 +2,79 @@
 
 //! Frontend: Popup content
 
-use crate::app::Message;
+use crate::app::{Confirmation, Message, PopupPage};
+use crate::config::{self, Config, ListDensity};
+use crate::fl;
+use crate::mime_icon::mime_icon;
+use crate::operation_error::OperationError;
+use crate::secure_erase::{self, SecureEraseProgress};
 use crate::trash_item_metadata::EnrichedTrashItem;
+use crate::trash_preview::TrashPreview;
 use crate::trash_status::TrashStatus;
-use crate::ui_items;
+use crate::{i18n, ui_error_banner, ui_items};
 use cosmic::applet::{menu_button, padded_control};
+use cosmic::iced::Length;
 use cosmic::iced::widget::{horizontal_rule, rule};
 use cosmic::prelude::*;
-use cosmic::widget::divider;
+use cosmic::widget::{divider, tooltip};
 use cosmic::{theme, widget}; // Native divider
+use std::ffi::OsString;
+use std::sync::LazyLock;
 
+/// Search field (focused with `/`)
+pub static SEARCH_INPUT: LazyLock<widget::Id> = LazyLock::new(|| widget::Id::new("trash-search"));
+
+#[allow(clippy::too_many_arguments, clippy::too_many_lines)]
 pub fn view<'a>(
     trash_status: &TrashStatus,
     trash_items: &'a [EnrichedTrashItem],
     sort_ascending: bool,
+    selected: Option<&OsString>,
+    focused: Option<&OsString>,
+    marked: &[OsString],
+    search_query: &'a str,
+    preview: Option<&'a TrashPreview>,
+    last_restored: Option<&std::path::Path>,
+    operation_errors: &'a [OperationError],
+    config: &'a Config,
+    confirmation: Option<&Confirmation>,
+    secure_progress: Option<&SecureEraseProgress>,
     _core: &cosmic::Core,
 ) -> Element<'a, Message> {
     let cosmic::cosmic_theme::Spacing {
         space_xxs, space_s, ..
     } = theme::active().cosmic().spacing;
 
-    let mut content = if trash_status.is_empty {
-        // Empty state: no header, start directly at buttons
-        widget::column().padding([8, 0])
-    } else {
+    let mut content = widget::column().padding([8, 0]);
+
+    // Error banner (recent failures, with retry/dismiss) above everything else
+    if let Some(banner) = ui_error_banner::view(operation_errors) {
+        content = content.push(padded_control(banner));
+    }
+
+    // Pending permanent deletion (Config::confirm_empty / confirm_delete)
+    if let Some(confirmation) = confirmation {
+        content = content.push(padded_control(confirmation_card(
+            confirmation,
+            trash_items,
+            &config.pinned_items,
+        )));
+    }
+
+    // Empty state: no header, start directly at buttons
+    if !trash_status.is_empty {
         // Title: padded_control applies lateral padding automatically
         let title_row = widget::container(padded_control(
             widget::row()
                 .push(widget::icon::from_name(trash_status.icon_name_dock()).size(32))
                 .push(
-                    widget::text::body("Trash")
+                    widget::text::body(fl!("trash"))
                         .class(cosmic::theme::Text::Accent)
                         .size(24),
                 )
                 .push(widget::horizontal_space())
                 .push(
-                    widget::text::body(format!("{} items", trash_status.item_count))
+                    widget::text::body(fl!("items-count", count = trash_status.item_count))
                         .align_y(cosmic::iced::alignment::Vertical::Bottom),
                 )
                 .spacing(8)
@@ -56,14 +93,44 @@ pub fn view<'a>(
         )))
         .padding([0, space_s]);
 
-        widget::column()
-            .padding([8, 0])
-            .push(title_row)
-            .push(accent_divider)
-            .push(padded_control(ui_items::view(trash_items, sort_ascending)))
+        content = content.push(title_row).push(accent_divider);
+
+        // Latest deletions, newest first, regardless of the list's sort order
+        if config.recent_count > 0 {
+            content = content.push(padded_control(recent_strip(
+                trash_items,
+                config.recent_count as usize,
+            )));
+        }
+
+        content = content.push(padded_control(
+            widget::search_input(fl!("search-trash"), search_query)
+                .id(SEARCH_INPUT.clone())
+                .on_input(Message::SearchChanged)
+                .on_clear(Message::SearchChanged(String::new())),
+        ));
+
+        // Marked items (Ctrl+A): bulk restore / delete
+        if !marked.is_empty() {
+            content = content.push(padded_control(marked_bar(trash_items, marked)));
+        }
+
+        content = content
+            .push(padded_control(ui_items::view(
+                trash_items,
+                sort_ascending,
+                selected,
+                focused,
+                marked,
+                search_query,
+                preview,
+                &config.pinned_items,
+                config.size_units,
+                config.list_density == ListDensity::Compact,
+            )))
             // Divider OUTSIDE items
-            .push(padded_control(divider::horizontal::default()).padding([0, space_s]))
-    };
+            .push(padded_control(divider::horizontal::default()).padding([0, space_s]));
+    }
 
     // Empty Trash button
     let empty_icon = if trash_status.is_empty {
@@ -72,39 +139,353 @@ pub fn view<'a>(
         "user-trash-full-symbolic"
     };
 
+    let pinned_count = pinned_count(trash_items, &config.pinned_items);
+    let can_empty = can_empty(trash_status, trash_items, &config.pinned_items);
+
     // Empty Trash button - conditional text
     let empty_text = if trash_status.is_empty {
-        "Trash is empty"
+        fl!("trash-is-empty")
+    } else if pinned_count > 0 {
+        fl!("empty-trash-except-pinned")
+    } else if config.secure_erase {
+        fl!("secure-empty-trash")
     } else {
-        "Empty trash..."
+        fl!("empty-trash-menu")
     };
 
+    // Running secure erase: item, progress bar and filesystem caveat
+    if let Some(progress) = secure_progress {
+        let mut status = widget::column()
+            .push(widget::text::caption(fl!(
+                "secure-erase-progress",
+                name = progress.current.clone(),
+                percent = i18n::format_decimal(f64::from(progress.fraction()) * 100.0, 0)
+            )))
+            .push(widget::progress_bar(0.0..=1.0, progress.fraction()).height(4))
+            .spacing(4);
+        if let Some(fs_type) = &progress.copy_on_write {
+            status = status.push(widget::text::caption(fl!(
+                "secure-erase-copy-on-write",
+                filesystem = fs_type.clone()
+            )));
+        }
+        content = content.push(padded_control(status));
+    }
+
+    content = content.push(
+        menu_button(
+            widget::row()
+                .push(widget::icon::from_name(empty_icon).size(16))
+                .push(widget::text::body(empty_text))
+                .spacing(12)
+                .align_y(cosmic::iced::Alignment::Center),
+        )
+        .on_press_maybe(
+            can_empty.then_some(Message::Confirm(Confirmation::Empty { secure: false })),
+        ), // Conditional
+    );
+
+    // Secure variant (already the default action when enabled in Config)
+    if !config.secure_erase && can_empty {
+        content = content.push(tooltip(
+            menu_button(
+                widget::row()
+                    .push(widget::icon::from_name("edit-delete-symbolic").size(16))
+                    .push(widget::text::body(fl!("secure-empty-trash")))
+                    .spacing(12)
+                    .align_y(cosmic::iced::Alignment::Center),
+            )
+            .on_press_maybe(
+                secure_progress
+                    .is_none()
+                    .then_some(Message::Confirm(Confirmation::Empty { secure: true })),
+            ),
+            widget::text(secure_erase::limitations()),
+            tooltip::Position::Bottom,
+        ));
+    }
+
     content = content
+        .push(padded_control(divider::horizontal::default()).padding([space_xxs, space_s])) // Divider entre buttons
         .push(
             menu_button(
                 widget::row()
-                    .push(widget::icon::from_name(empty_icon).size(16))
-                    .push(widget::text::body(empty_text))
+                    .push(widget::icon::from_name("document-save-symbolic").size(16))
+                    .push(widget::text::body(fl!("export-list")))
                     .spacing(12)
                     .align_y(cosmic::iced::Alignment::Center),
             )
-            .on_press_maybe(if trash_status.is_empty {
-                None
-            } else {
-                Some(Message::EmptyTrash)
-            }), // Conditional
+            .on_press_maybe((!trash_status.is_empty).then_some(Message::ExportList)),
         )
-        .push(padded_control(divider::horizontal::default()).padding([space_xxs, space_s])) // Divider entre buttons
         .push(
             menu_button(
                 widget::row()
                     .push(widget::icon::from_name("folder-open-symbolic").size(16))
-                    .push(widget::text::body("Open trash in files..."))
+                    .push(widget::text::body(fl!("open-trash")))
                     .spacing(12)
                     .align_y(cosmic::iced::Alignment::Center),
             )
             .on_press(Message::OpenTrashFolder),
         );
 
+    // Shortcut to the last restored item (highlighted in cosmic-files)
+    if let Some(path) = last_restored {
+        content = content.push(
+            menu_button(
+                widget::row()
+                    .push(widget::icon::from_name("edit-undo-symbolic").size(16))
+                    .push(widget::text::body(fl!("show-restored")))
+                    .spacing(12)
+                    .align_y(cosmic::iced::Alignment::Center),
+            )
+            .on_press(Message::RevealOriginal(path.to_path_buf())),
+        );
+    }
+
+    // Settings (gear)
+    content = content
+        .push(padded_control(divider::horizontal::default()).padding([space_xxs, space_s]))
+        .push(
+            menu_button(
+                widget::row()
+                    .push(widget::icon::from_name("emblem-system-symbolic").size(16))
+                    .push(widget::text::body(fl!("settings-menu")))
+                    .spacing(12)
+                    .align_y(cosmic::iced::Alignment::Center),
+            )
+            .on_press(Message::ShowPage(PopupPage::Settings)),
+        );
+
     _core.applet.popup_container(content).into()
 }
+
+/// "Recently deleted": the `count` newest items with one-click restore
+fn recent_strip<'a>(trash_items: &[EnrichedTrashItem], count: usize) -> Element<'a, Message> {
+    let now = chrono::Utc::now().timestamp();
+    let mut strip = widget::column()
+        .push(widget::text::heading(fl!("recently-deleted")))
+        .spacing(4);
+
+    for enriched in EnrichedTrashItem::most_recent(trash_items, count) {
+        let name = enriched.item.name.to_string_lossy().to_string();
+        strip = strip.push(
+            widget::row()
+                .push(widget::icon::icon(mime_icon(enriched.mime.clone(), 16)).size(16))
+                .push(widget::text::body(name.clone()).width(Length::Fill))
+                .push(widget::text::caption(fl!(
+                    "deleted-ago",
+                    age = i18n::format_duration(now - enriched.item.time_deleted)
+                )))
+                .push(tooltip(
+                    widget::button::icon(widget::icon::from_name("edit-undo-symbolic").size(16))
+                        .on_press(Message::RestoreItem(enriched.clone()))
+                        .name(fl!("a11y-restore-item", name = name)),
+                    widget::text(fl!("action-restore")),
+                    tooltip::Position::Bottom,
+                ))
+                .spacing(8)
+                .align_y(cosmic::iced::Alignment::Center),
+        );
+    }
+
+    strip.into()
+}
+
+/// Compact right-click menu: Empty, Open, Settings
+pub fn context_menu<'a>(
+    trash_status: &TrashStatus,
+    trash_items: &[EnrichedTrashItem],
+    config: &Config,
+    core: &cosmic::Core,
+) -> Element<'a, Message> {
+    let empty_icon = if trash_status.is_empty {
+        "user-trash-symbolic"
+    } else {
+        "user-trash-full-symbolic"
+    };
+
+    let content = widget::column()
+        .push(
+            menu_button(
+                widget::row()
+                    .push(widget::icon::from_name(empty_icon).size(16))
+                    .push(widget::text::body(fl!("empty-trash-menu")))
+                    .spacing(12)
+                    .align_y(cosmic::iced::Alignment::Center),
+            )
+            .on_press_maybe(
+                can_empty(trash_status, trash_items, &config.pinned_items)
+                    .then_some(Message::Confirm(Confirmation::Empty { secure: false })),
+            ),
+        )
+        .push(
+            menu_button(
+                widget::row()
+                    .push(widget::icon::from_name("folder-open-symbolic").size(16))
+                    .push(widget::text::body(fl!("open-trash")))
+                    .spacing(12)
+                    .align_y(cosmic::iced::Alignment::Center),
+            )
+            .on_press(Message::OpenTrashFolder),
+        )
+        .push(
+            menu_button(
+                widget::row()
+                    .push(widget::icon::from_name("emblem-system-symbolic").size(16))
+                    .push(widget::text::body(fl!("settings-menu")))
+                    .spacing(12)
+                    .align_y(cosmic::iced::Alignment::Center),
+            )
+            .on_press(Message::ShowPage(PopupPage::Settings)),
+        )
+        .padding([8, 0]);
+
+    core.applet.popup_container(content).into()
+}
+
+/// "N selected" with Restore / Delete / Clear for the marked items
+fn marked_bar<'a>(trash_items: &[EnrichedTrashItem], marked: &[OsString]) -> Element<'a, Message> {
+    let items: Vec<EnrichedTrashItem> = trash_items
+        .iter()
+        .filter(|enriched| marked.contains(&enriched.item.id))
+        .cloned()
+        .collect();
+
+    widget::row()
+        .push(widget::text::body(fl!("marked-count", count = items.len())).width(Length::Fill))
+        .push(widget::button::standard(fl!("clear")).on_press(Message::ClearMarked))
+        .push(
+            widget::button::standard(fl!("action-restore"))
+                .on_press(Message::RestoreItems(items.clone())),
+        )
+        .push(
+            widget::button::destructive(fl!("action-delete"))
+                .on_press(Message::Confirm(Confirmation::DeleteMarked { items })),
+        )
+        .spacing(8)
+        .align_y(cosmic::iced::Alignment::Center)
+        .into()
+}
+
+/// Whether "Empty trash" has anything to do
+///
+/// Pinned items are never emptied (nothing to do if every item is pinned;
+/// the list may still be loading, so an empty list does not disable it).
+fn can_empty(
+    trash_status: &TrashStatus,
+    trash_items: &[EnrichedTrashItem],
+    pinned: &[String],
+) -> bool {
+    !trash_status.is_empty && pinned_count(trash_items, pinned) < trash_items.len().max(1)
+}
+
+fn pinned_count(trash_items: &[EnrichedTrashItem], pinned: &[String]) -> usize {
+    trash_items
+        .iter()
+        .filter(|enriched| config::is_pinned(pinned, &enriched.item))
+        .count()
+}
+
+/// "Delete permanently?" card with Cancel / confirm buttons
+pub fn confirmation_card<'a>(
+    confirmation: &Confirmation,
+    trash_items: &[EnrichedTrashItem],
+    pinned: &[String],
+) -> Element<'a, Message> {
+    let (question, action, secure) = match confirmation {
+        Confirmation::Empty { secure } => {
+            let count = trash_items.len() - pinned_count(trash_items, pinned);
+            (
+                fl!("confirm-empty", count = count),
+                fl!("confirm-empty-action"),
+                *secure,
+            )
+        }
+        Confirmation::Delete { item, secure } => (
+            fl!(
+                "confirm-delete",
+                name = item.item.name.to_string_lossy().to_string()
+            ),
+            fl!("action-delete"),
+            *secure,
+        ),
+        Confirmation::DeleteMarked { items } => (
+            fl!("confirm-delete-marked", count = items.len()),
+            fl!("action-delete"),
+            false,
+        ),
+        Confirmation::AddRule { matched, .. } => {
+            let count = *matched;
+            (
+                fl!("confirm-add-rule", count = count),
+                fl!("rule-add"),
+                false,
+            )
+        }
+        Confirmation::RemoveRule { matched, .. } => {
+            let count = *matched;
+            (
+                fl!("confirm-remove-rule", count = count),
+                fl!("rule-remove"),
+                false,
+            )
+        }
+        Confirmation::ChangeSetting { matched, .. } => {
+            let count = *matched;
+            (
+                fl!("confirm-change-setting", count = count),
+                fl!("confirm-change-setting-action"),
+                false,
+            )
+        }
+    };
+
+    let mut column = widget::column()
+        .push(widget::text::body(question))
+        .push(widget::text::caption(fl!("cannot-undo")))
+        .spacing(8);
+    if secure {
+        column = column.push(widget::text::caption(secure_erase::limitations()));
+    }
+    column = column.push(
+        widget::row()
+            .push(widget::horizontal_space())
+            .push(widget::button::standard(fl!("cancel")).on_press(Message::CancelPending))
+            .push(widget::button::destructive(action).on_press(Message::ConfirmPending))
+            .spacing(8),
+    );
+
+    widget::container(column)
+        .padding(12)
+        .width(Length::Fill)
+        .class(cosmic::theme::Container::Card)
+        .into()
+}
+
+/// Secondary popup page: back button + title above `body`
+pub fn page<'a>(
+    title: String,
+    back: PopupPage,
+    body: Element<'a, Message>,
+    core: &cosmic::Core,
+) -> Element<'a, Message> {
+    let header = padded_control(
+        widget::row()
+            .push(
+                widget::button::icon(widget::icon::from_name("go-previous-symbolic").size(16))
+                    .on_press(Message::ShowPage(back))
+                    .name(fl!("a11y-back")),
+            )
+            .push(widget::text::heading(title))
+            .spacing(8)
+            .align_y(cosmic::iced::Alignment::Center),
+    );
+
+    let content = widget::column()
+        .push(header)
+        .push(padded_control(divider::horizontal::default()))
+        .push(widget::scrollable(body).height(Length::Shrink))
+        .padding([8, 0]);
+
+    core.applet.popup_container(content).into()
+}
@@ -0,0 +1,95 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! UI Module: Inline quick-look preview
+//!
+//! Rendered below the selected row in `ui_items::view`
+
+use crate::app::Message;
+use crate::fl;
+use crate::trash_preview::{PreviewContent, TrashPreview};
+use cosmic::iced::{ContentFit, Length};
+use cosmic::prelude::*;
+use cosmic::widget;
+
+/// Max height of image previews
+const IMAGE_HEIGHT: f32 = 120.0;
+
+/// Renders the preview pane (content + metadata), or a placeholder while loading
+#[must_use]
+pub fn view(preview: Option<&TrashPreview>) -> Element<'_, Message> {
+    let Some(preview) = preview else {
+        return widget::container(widget::text::caption(fl!("preview-loading")))
+            .padding([8, 12])
+            .into();
+    };
+
+    let mut column = widget::column().spacing(8).padding([8, 12]);
+
+    match &preview.content {
+        PreviewContent::Text(text) => {
+            column = column.push(
+                widget::container(widget::text::monotext(text.as_str()).size(11))
+                    .padding(8)
+                    .width(Length::Fill)
+                    .class(cosmic::theme::Container::Card),
+            );
+        }
+        PreviewContent::Image(path) => {
+            column = column.push(
+                widget::image(widget::image::Handle::from_path(path))
+                    .content_fit(ContentFit::Contain)
+                    .height(Length::Fixed(IMAGE_HEIGHT))
+                    .width(Length::Fill),
+            );
+        }
+        PreviewContent::Directory { entries, total } => {
+            let mut listing = widget::column().spacing(2);
+            for (name, is_dir) in entries {
+                let icon = if *is_dir {
+                    "folder-symbolic"
+                } else {
+                    "text-x-generic-symbolic"
+                };
+                listing = listing.push(
+                    widget::row()
+                        .push(widget::icon::from_name(icon).size(12))
+                        .push(widget::text::caption(name.as_str()))
+                        .spacing(6)
+                        .align_y(cosmic::iced::Alignment::Center),
+                );
+            }
+            if *total > entries.len() {
+                let more = total - entries.len();
+                listing = listing.push(widget::text::caption(fl!("preview-more", count = more)));
+            }
+            column = column.push(listing);
+        }
+        PreviewContent::None => {}
+    }
+
+    // Metadata: original path, permissions, modification time
+    column = column.push(metadata_row(
+        fl!("preview-original-location"),
+        preview.original_path.display().to_string(),
+    ));
+    if let Some(permissions) = &preview.permissions {
+        column = column.push(metadata_row(
+            fl!("preview-permissions"),
+            permissions.clone(),
+        ));
+    }
+    if let Some(modified) = &preview.modified {
+        column = column.push(metadata_row(fl!("preview-modified"), modified.clone()));
+    }
+
+    column.into()
+}
+
+/// Label: value caption row
+fn metadata_row<'a>(label: String, value: String) -> Element<'a, Message> {
+    widget::row()
+        .push(widget::text::caption_heading(label).width(Length::Fixed(110.0)))
+        .push(widget::text::caption(value).width(Length::Fill))
+        .spacing(8)
+        .into()
+}
@@ -0,0 +1,122 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Frontend: Cleanup rules editor
+//!
+//! Lists `Config::cleanup_rules` with a remove button and a form to add rules.
+//! A "purge immediately" rule that matches items already in the trash is only
+//! added, and a "keep" rule protecting such items only removed, after the
+//! confirmation card shown above the form.
+
+use crate::app::Message;
+use crate::cleanup_rules::{CleanupRule, PatternSyntax, RuleAction, RuleTarget};
+use crate::fl;
+use cosmic::applet::padded_control;
+use cosmic::iced::Length;
+use cosmic::prelude::*;
+use cosmic::widget::{self, tooltip};
+use std::sync::LazyLock;
+
+// Dropdown labels, in `ALL` order
+static SYNTAX_NAMES: LazyLock<Vec<String>> = LazyLock::new(|| {
+    PatternSyntax::ALL
+        .into_iter()
+        .map(PatternSyntax::name)
+        .collect()
+});
+static TARGET_NAMES: LazyLock<Vec<String>> =
+    LazyLock::new(|| RuleTarget::ALL.into_iter().map(RuleTarget::name).collect());
+static ACTION_NAMES: LazyLock<Vec<String>> =
+    LazyLock::new(|| RuleAction::ALL.into_iter().map(RuleAction::name).collect());
+
+/// Renders the rules list and the "add rule" form
+#[must_use]
+pub fn view<'a>(
+    rules: &'a [CleanupRule],
+    draft: &'a CleanupRule,
+    draft_error: Option<&'a str>,
+    confirmation: Option<Element<'a, Message>>,
+) -> Element<'a, Message> {
+    let mut list = widget::column().spacing(8);
+
+    if rules.is_empty() {
+        list = list.push(widget::text::caption(fl!("rules-empty")));
+    }
+    for (index, rule) in rules.iter().enumerate() {
+        list = list.push(
+            widget::row()
+                .push(
+                    widget::column()
+                        .push(widget::text::body(rule.pattern.as_str()))
+                        .push(widget::text::caption(format!(
+                            "{} · {} · {}",
+                            rule.target.name(),
+                            rule.syntax.name(),
+                            rule.action.name()
+                        )))
+                        .spacing(2)
+                        .width(Length::Fill),
+                )
+                .push(tooltip(
+                    widget::button::icon(widget::icon::from_name("edit-delete-symbolic").size(16))
+                        .on_press(Message::RemoveRule(index))
+                        .name(fl!("a11y-remove-rule", pattern = rule.pattern.clone())),
+                    widget::text(fl!("rule-remove")),
+                    tooltip::Position::Bottom,
+                ))
+                .spacing(8)
+                .align_y(cosmic::iced::Alignment::Center),
+        );
+    }
+
+    let placeholder = match draft.target {
+        RuleTarget::Name => "*.tmp",
+        RuleTarget::Location => "~/Documents/contracts",
+    };
+
+    let mut form = widget::column()
+        .push(widget::text::heading(fl!("rule-add")))
+        .push(
+            widget::text_input(placeholder, draft.pattern.as_str())
+                .on_input(Message::RuleDraftPattern),
+        )
+        .push(
+            widget::row()
+                .push(widget::dropdown(
+                    &TARGET_NAMES,
+                    RuleTarget::ALL.iter().position(|t| *t == draft.target),
+                    Message::RuleDraftTarget,
+                ))
+                .push(widget::dropdown(
+                    &SYNTAX_NAMES,
+                    PatternSyntax::ALL.iter().position(|s| *s == draft.syntax),
+                    Message::RuleDraftSyntax,
+                ))
+                .spacing(8),
+        )
+        .push(widget::dropdown(
+            &ACTION_NAMES,
+            RuleAction::ALL.iter().position(|a| *a == draft.action),
+            Message::RuleDraftAction,
+        ))
+        .spacing(8);
+
+    if let Some(error) = draft_error {
+        form = form.push(widget::text::caption(error).class(cosmic::theme::Text::Accent));
+    }
+
+    form = form.push(
+        widget::row().push(widget::horizontal_space()).push(
+            widget::button::suggested(fl!("rule-add"))
+                .on_press_maybe((!draft.pattern.trim().is_empty()).then_some(Message::AddRule)),
+        ),
+    );
+
+    let mut content = widget::column()
+        .push(padded_control(list))
+        .push(padded_control(widget::divider::horizontal::default()));
+    if let Some(confirmation) = confirmation {
+        content = content.push(padded_control(confirmation));
+    }
+
+    content.push(padded_control(form)).into()
+}
@@ -0,0 +1,319 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Frontend: Settings page
+//!
+//! Edits `Config` in place; every change is persisted through `cosmic_config`
+//! (see `Message::ChangeSetting`), and external edits arrive via `UpdateConfig`.
+//! The custom file manager command is kept as a draft while typing and saved
+//! on Enter or when leaving the page. A retention change that would purge
+//! items already in the trash waits for the confirmation card below it.
+
+use crate::app::{Message, PopupPage};
+use crate::config::{Config, ListDensity, PanelAction, PanelIndicator, SettingChange, SortOrder};
+use crate::file_manager::FileManager;
+use crate::size_format::{self, SizeUnits};
+use crate::{fl, i18n};
+use cosmic::applet::padded_control;
+use cosmic::prelude::*;
+use cosmic::widget;
+use std::sync::LazyLock;
+
+// Preset values (dropdown order)
+const RETENTION_DAYS: &[u32] = &[0, 7, 14, 30, 60, 90];
+const THRESHOLD_MB: &[u64] = &[0, 512, 1024, 5 * 1024, 10 * 1024];
+const PASSES: &[u32] = &[1, 3, 7];
+const RECENT_COUNTS: &[u32] = &[0, 3, 5, 10];
+const QUOTA_MB: &[u64] = &[512, 1024, 5 * 1024, 10 * 1024, 50 * 1024];
+
+// Dropdown labels, localized once (the language is chosen at startup)
+static DENSITY_NAMES: LazyLock<Vec<String>> = LazyLock::new(|| {
+    ListDensity::ALL
+        .into_iter()
+        .map(ListDensity::name)
+        .collect()
+});
+static SORT_NAMES: LazyLock<Vec<String>> =
+    LazyLock::new(|| SortOrder::ALL.into_iter().map(SortOrder::name).collect());
+static RECENT_NAMES: LazyLock<Vec<String>> = LazyLock::new(|| {
+    RECENT_COUNTS
+        .iter()
+        .map(|count| match count {
+            0 => fl!("recent-hidden"),
+            count => i18n::format_count(u64::from(*count)),
+        })
+        .collect()
+});
+static SIZE_UNITS_NAMES: LazyLock<Vec<String>> =
+    LazyLock::new(|| SizeUnits::ALL.into_iter().map(SizeUnits::name).collect());
+static INDICATOR_NAMES: LazyLock<Vec<String>> = LazyLock::new(|| {
+    PanelIndicator::ALL
+        .into_iter()
+        .map(PanelIndicator::name)
+        .collect()
+});
+static PANEL_ACTION_NAMES: LazyLock<Vec<String>> = LazyLock::new(|| {
+    PanelAction::ALL
+        .into_iter()
+        .map(PanelAction::name)
+        .collect()
+});
+static FILE_MANAGER_NAMES: LazyLock<Vec<String>> = LazyLock::new(|| {
+    FileManager::ALL
+        .into_iter()
+        .map(FileManager::name)
+        .collect()
+});
+static RETENTION_NAMES: LazyLock<Vec<String>> = LazyLock::new(|| {
+    RETENTION_DAYS
+        .iter()
+        .map(|&days| match days {
+            0 => fl!("retention-never"),
+            days => fl!("retention-days", days = days),
+        })
+        .collect()
+});
+// Thresholds and quotas are stored in MiB, so they read best in IEC units
+static THRESHOLD_NAMES: LazyLock<Vec<String>> = LazyLock::new(|| {
+    THRESHOLD_MB
+        .iter()
+        .map(|mb| match mb {
+            0 => fl!("threshold-off"),
+            mb => size_format::format(mb * 1024 * 1024, SizeUnits::Iec),
+        })
+        .collect()
+});
+static QUOTA_NAMES: LazyLock<Vec<String>> = LazyLock::new(|| {
+    QUOTA_MB
+        .iter()
+        .map(|mb| size_format::format(mb * 1024 * 1024, SizeUnits::Iec))
+        .collect()
+});
+static PASSES_NAMES: LazyLock<Vec<String>> = LazyLock::new(|| {
+    PASSES
+        .iter()
+        .map(|&passes| fl!("passes", count = passes))
+        .collect()
+});
+
+/// Renders the settings sections for `config`
+#[must_use]
+pub fn view<'a>(
+    config: &'a Config,
+    custom_file_manager_draft: Option<&'a str>,
+    confirmation: Option<Element<'a, Message>>,
+) -> Element<'a, Message> {
+    let list = section(fl!("settings-items-list"))
+        .push(dropdown_row(
+            fl!("settings-density"),
+            &DENSITY_NAMES,
+            ListDensity::ALL
+                .iter()
+                .position(|density| *density == config.list_density),
+            |index| SettingChange::ListDensity(ListDensity::ALL[index]),
+        ))
+        .push(dropdown_row(
+            fl!("settings-default-order"),
+            &SORT_NAMES,
+            SortOrder::ALL
+                .iter()
+                .position(|order| *order == config.default_sort),
+            |index| SettingChange::DefaultSort(SortOrder::ALL[index]),
+        ))
+        .push(dropdown_row(
+            fl!("settings-recent"),
+            &RECENT_NAMES,
+            RECENT_COUNTS
+                .iter()
+                .position(|count| *count == config.recent_count),
+            |index| SettingChange::RecentCount(RECENT_COUNTS[index]),
+        ))
+        .push(dropdown_row(
+            fl!("settings-size-units"),
+            &SIZE_UNITS_NAMES,
+            SizeUnits::ALL
+                .iter()
+                .position(|units| *units == config.size_units),
+            |index| SettingChange::SizeUnits(SizeUnits::ALL[index]),
+        ));
+
+    let mut panel_icon = section(fl!("settings-panel-icon")).push(dropdown_row(
+        fl!("settings-indicator"),
+        &INDICATOR_NAMES,
+        PanelIndicator::ALL
+            .iter()
+            .position(|indicator| *indicator == config.panel_indicator),
+        |index| SettingChange::PanelIndicator(PanelIndicator::ALL[index]),
+    ));
+    if config.panel_indicator == PanelIndicator::FillLevel {
+        panel_icon = panel_icon.push(dropdown_row(
+            fl!("settings-quota"),
+            &QUOTA_NAMES,
+            QUOTA_MB.iter().position(|mb| *mb == config.quota_mb),
+            |index| SettingChange::QuotaMb(QUOTA_MB[index]),
+        ));
+    }
+    panel_icon = panel_icon
+        .push(dropdown_row(
+            fl!("settings-middle-click"),
+            &PANEL_ACTION_NAMES,
+            PanelAction::ALL
+                .iter()
+                .position(|action| *action == config.middle_click),
+            |index| SettingChange::MiddleClick(PanelAction::ALL[index]),
+        ))
+        .push(dropdown_row(
+            fl!("settings-right-click"),
+            &PANEL_ACTION_NAMES,
+            PanelAction::ALL
+                .iter()
+                .position(|action| *action == config.right_click),
+            |index| SettingChange::RightClick(PanelAction::ALL[index]),
+        ))
+        .push(toggle_row(
+            fl!("settings-scroll-recent"),
+            config.scroll_recent,
+            SettingChange::ScrollRecent,
+        ));
+
+    let confirmations = section(fl!("settings-confirmations"))
+        .push(toggle_row(
+            fl!("settings-confirm-empty"),
+            config.confirm_empty,
+            SettingChange::ConfirmEmpty,
+        ))
+        .push(toggle_row(
+            fl!("settings-confirm-delete"),
+            config.confirm_delete,
+            SettingChange::ConfirmDelete,
+        ));
+
+    let notifications = section(fl!("settings-notifications"))
+        .push(toggle_row(
+            fl!("settings-notify-empty"),
+            config.notify_empty,
+            SettingChange::NotifyEmpty,
+        ))
+        .push(toggle_row(
+            fl!("settings-notify-auto-purge"),
+            config.notify_auto_purge,
+            SettingChange::NotifyAutoPurge,
+        ))
+        .push(toggle_row(
+            fl!("settings-notify-restore"),
+            config.notify_restore,
+            SettingChange::NotifyRestore,
+        ))
+        .push(toggle_row(
+            fl!("settings-notify-restore-failure"),
+            config.notify_restore_failure,
+            SettingChange::NotifyRestoreFailure,
+        ))
+        .push(dropdown_row(
+            fl!("settings-size-threshold"),
+            &THRESHOLD_NAMES,
+            THRESHOLD_MB
+                .iter()
+                .position(|mb| *mb == config.size_threshold_mb),
+            |index| SettingChange::SizeThresholdMb(THRESHOLD_MB[index]),
+        ));
+
+    let mut cleanup = section(fl!("settings-cleanup")).push(dropdown_row(
+        fl!("settings-retention"),
+        &RETENTION_NAMES,
+        RETENTION_DAYS
+            .iter()
+            .position(|days| *days == config.retention_days),
+        |index| SettingChange::RetentionDays(RETENTION_DAYS[index]),
+    ));
+    if let Some(confirmation) = confirmation {
+        cleanup = cleanup.push(confirmation);
+    }
+    cleanup = cleanup.push(widget::settings::item(
+        fl!("cleanup-rules"),
+        widget::button::standard(fl!(
+            "settings-rules-button",
+            count = config.cleanup_rules.len()
+        ))
+        .on_press(Message::ShowPage(PopupPage::CleanupRules)),
+    ));
+
+    let deletion = section(fl!("settings-deletion"))
+        .push(toggle_row(
+            fl!("settings-secure-erase"),
+            config.secure_erase,
+            SettingChange::SecureErase,
+        ))
+        .push(dropdown_row(
+            fl!("settings-passes"),
+            &PASSES_NAMES,
+            PASSES.iter().position(|passes| {
+                *passes == crate::secure_erase::effective_passes(config.secure_erase_passes)
+            }),
+            |index| SettingChange::SecureErasePasses(PASSES[index]),
+        ))
+        .push(toggle_row(
+            fl!("settings-read-only"),
+            config.open_read_only,
+            SettingChange::OpenReadOnly,
+        ));
+
+    let mut file_manager = section(fl!("settings-file-manager")).push(dropdown_row(
+        fl!("settings-application"),
+        &FILE_MANAGER_NAMES,
+        FileManager::ALL
+            .iter()
+            .position(|file_manager| *file_manager == config.file_manager),
+        |index| SettingChange::FileManager(FileManager::ALL[index]),
+    ));
+    if config.file_manager == FileManager::Custom {
+        let template = custom_file_manager_draft.unwrap_or(config.custom_file_manager.as_str());
+        file_manager = file_manager.push(
+            widget::text_input("my-files {uri}", template)
+                .on_input(Message::CustomFileManagerDraft)
+                .on_submit(|_| Message::SaveCustomFileManager),
+        );
+    }
+
+    widget::column()
+        .push(padded_control(list))
+        .push(padded_control(panel_icon))
+        .push(padded_control(confirmations))
+        .push(padded_control(notifications))
+        .push(padded_control(cleanup))
+        .push(padded_control(deletion))
+        .push(padded_control(file_manager))
+        .into()
+}
+
+fn section<'a>(title: String) -> widget::Column<'a, Message> {
+    widget::column()
+        .push(widget::text::heading(title))
+        .spacing(8)
+}
+
+fn toggle_row<'a>(
+    label: String,
+    value: bool,
+    change: fn(bool) -> SettingChange,
+) -> Element<'a, Message> {
+    widget::settings::item(
+        label,
+        widget::toggler(value).on_toggle(move |value| Message::ChangeSetting(change(value))),
+    )
+    .into()
+}
+
+fn dropdown_row<'a>(
+    label: String,
+    names: &'static [String],
+    selected: Option<usize>,
+    change: fn(usize) -> SettingChange,
+) -> Element<'a, Message> {
+    widget::settings::item(
+        label,
+        widget::dropdown(names, selected, move |index| {
+            Message::ChangeSetting(change(index))
+        }),
+    )
+    .into()
+}